@@ -0,0 +1,49 @@
+//! Common `autocomplete` tokens, to avoid typos when setting the `autocomplete` prop.
+//!
+//! Tokens can be combined in a single value, e.g. `"section-billing address-line1"` or
+//! `"shipping postal-code"`, which is passed to the input unchanged.
+
+/// The full name.
+pub const NAME: &str = "name";
+/// The given (first) name.
+pub const GIVEN_NAME: &str = "given-name";
+/// The family (last) name.
+pub const FAMILY_NAME: &str = "family-name";
+/// An email address.
+pub const EMAIL: &str = "email";
+/// A username.
+pub const USERNAME: &str = "username";
+/// A new password, e.g. on sign-up forms.
+pub const NEW_PASSWORD: &str = "new-password";
+/// The current password, e.g. on sign-in forms.
+pub const CURRENT_PASSWORD: &str = "current-password";
+/// A one-time code, e.g. received by SMS.
+pub const ONE_TIME_CODE: &str = "one-time-code";
+/// A full phone number, including the country code.
+pub const TEL: &str = "tel";
+/// The national part of a phone number.
+pub const TEL_NATIONAL: &str = "tel-national";
+/// A street address, possibly on several lines.
+pub const STREET_ADDRESS: &str = "street-address";
+/// The first line of a street address.
+pub const ADDRESS_LINE1: &str = "address-line1";
+/// The second line of a street address.
+pub const ADDRESS_LINE2: &str = "address-line2";
+/// The city or locality.
+pub const ADDRESS_LEVEL2: &str = "address-level2";
+/// A postal code.
+pub const POSTAL_CODE: &str = "postal-code";
+/// A country name.
+pub const COUNTRY_NAME: &str = "country-name";
+/// An organization or company name.
+pub const ORGANIZATION: &str = "organization";
+/// The name on a payment card.
+pub const CC_NAME: &str = "cc-name";
+/// A payment card number.
+pub const CC_NUMBER: &str = "cc-number";
+/// The expiration date of a payment card.
+pub const CC_EXP: &str = "cc-exp";
+/// The security code of a payment card.
+pub const CC_CSC: &str = "cc-csc";
+/// Disables autofill.
+pub const OFF: &str = "off";
@@ -0,0 +1,245 @@
+use yew::prelude::*;
+
+/// Props for the combobox.
+#[derive(Properties, PartialEq, Clone)]
+pub struct ComboboxProps {
+    /// The options to choose from, as `(value, label)` pairs, e.g. `("rust", "Rust")`.
+    pub options: Vec<(&'static str, &'static str)>,
+
+    /// State handle holding the value of the selected option.
+    pub value_handle: UseStateHandle<String>,
+
+    /// The name of the combobox, also used to derive the IDs of its elements.
+    pub name: &'static str,
+
+    /// The label to be displayed for the combobox.
+    #[prop_or_default]
+    pub label: &'static str,
+
+    /// The placeholder text to be displayed in the input element.
+    #[prop_or_default]
+    pub input_placeholder: &'static str,
+
+    /// The text displayed in the list when no option matches the typed text.
+    #[prop_or("No results")]
+    pub no_results_text: &'static str,
+
+    /// A callback receiving the value of the newly selected option.
+    #[prop_or_default]
+    pub on_select: Callback<String>,
+
+    /// The CSS class to be applied to the container.
+    #[prop_or_default]
+    pub form_input_class: &'static str,
+
+    /// The CSS class to be applied to the label element.
+    #[prop_or_default]
+    pub form_input_label_class: &'static str,
+
+    /// The CSS class to be applied to the input element.
+    #[prop_or_default]
+    pub form_input_input_class: &'static str,
+
+    /// The CSS class to be applied to the list of options.
+    #[prop_or_default]
+    pub listbox_class: &'static str,
+
+    /// The CSS class to be applied to every option.
+    #[prop_or_default]
+    pub option_class: &'static str,
+
+    /// The CSS class added to the option highlighted with the keyboard.
+    #[prop_or("active")]
+    pub option_active_class: &'static str,
+}
+
+/// custom_combobox
+/// A text input filtering a list of options as the user types, following the ARIA combobox pattern.
+///
+/// The arrow keys, Home and End move through the matching options, Enter selects the highlighted one and
+/// Escape closes the list. Leaving the input restores the label of the selected option.
+///
+/// # Examples
+/// ```
+/// use input_yew::CustomCombobox;
+/// use yew::prelude::*;
+///
+/// #[function_component(LanguagePicker)]
+/// fn language_picker() -> Html {
+///     let language_handle = use_state(String::default);
+///
+///     html! {
+///         <CustomCombobox
+///           name={"language"}
+///           label={"Language"}
+///           options={vec![("rust", "Rust"), ("go", "Go"), ("zig", "Zig")]}
+///           value_handle={language_handle}
+///         />
+///     }
+/// }
+/// ```
+#[function_component(CustomCombobox)]
+pub fn custom_combobox(props: &ComboboxProps) -> Html {
+    let selected_label = props
+        .options
+        .iter()
+        .find(|(value, _)| *value == props.value_handle.as_str())
+        .map(|(_, label)| *label)
+        .unwrap_or_default();
+
+    let query_handle = use_state(|| selected_label.to_string());
+    let filtering_handle = use_state(|| false);
+    let open_handle = use_state(|| false);
+    let active_handle = use_state(|| 0_usize);
+    let open = *open_handle;
+
+    // The whole list is shown until the user starts typing
+    let query = query_handle.to_lowercase();
+    let matches: Vec<(&'static str, &'static str)> = props
+        .options
+        .iter()
+        .filter(|(_, label)| !*filtering_handle || label.to_lowercase().contains(&query))
+        .copied()
+        .collect();
+    let active = (*active_handle).min(matches.len().saturating_sub(1));
+
+    let listbox_id = format!("{}-listbox", props.name);
+    let option_id = |index: usize| format!("{}-option-{}", props.name, index);
+
+    let select = {
+        let value_handle = props.value_handle.clone();
+        let query_handle = query_handle.clone();
+        let filtering_handle = filtering_handle.clone();
+        let open_handle = open_handle.clone();
+        let on_select = props.on_select.clone();
+        Callback::from(move |(value, label): (&'static str, &'static str)| {
+            value_handle.set(value.to_string());
+            query_handle.set(label.to_string());
+            filtering_handle.set(false);
+            open_handle.set(false);
+            on_select.emit(value.to_string());
+        })
+    };
+
+    let oninput = {
+        let query_handle = query_handle.clone();
+        let filtering_handle = filtering_handle.clone();
+        let open_handle = open_handle.clone();
+        let active_handle = active_handle.clone();
+        Callback::from(move |event: InputEvent| {
+            let input: web_sys::HtmlInputElement = event.target_unchecked_into();
+            query_handle.set(input.value());
+            filtering_handle.set(true);
+            open_handle.set(true);
+            active_handle.set(0);
+        })
+    };
+
+    let onkeydown = {
+        let open_handle = open_handle.clone();
+        let active_handle = active_handle.clone();
+        let select = select.clone();
+        let matches = matches.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            let last = matches.len().saturating_sub(1);
+            match event.key().as_str() {
+                "ArrowDown" => {
+                    event.prevent_default();
+                    if open {
+                        active_handle.set((active + 1).min(last));
+                    }
+                    open_handle.set(true);
+                }
+                "ArrowUp" => {
+                    event.prevent_default();
+                    active_handle.set(active.saturating_sub(1));
+                    open_handle.set(true);
+                }
+                "Home" if open => {
+                    event.prevent_default();
+                    active_handle.set(0);
+                }
+                "End" if open => {
+                    event.prevent_default();
+                    active_handle.set(last);
+                }
+                "Enter" if open => {
+                    if let Some(option) = matches.get(active) {
+                        event.prevent_default();
+                        select.emit(*option);
+                    }
+                }
+                "Escape" => open_handle.set(false),
+                _ => {}
+            }
+        })
+    };
+
+    let onfocus = {
+        let open_handle = open_handle.clone();
+        Callback::from(move |_: FocusEvent| open_handle.set(true))
+    };
+
+    let onblur = {
+        let query_handle = query_handle.clone();
+        let filtering_handle = filtering_handle.clone();
+        Callback::from(move |_: FocusEvent| {
+            open_handle.set(false);
+            filtering_handle.set(false);
+            query_handle.set(selected_label.to_string());
+        })
+    };
+
+    html! {
+        <div class={props.form_input_class}>
+            <label class={props.form_input_label_class} for={props.name}>{ props.label }</label>
+            <input
+                type="text"
+                id={props.name}
+                name={props.name}
+                class={props.form_input_input_class}
+                role="combobox"
+                aria-autocomplete="list"
+                aria-expanded={open.to_string()}
+                aria-controls={listbox_id.clone()}
+                aria-activedescendant={(open && !matches.is_empty()).then(|| option_id(active))}
+                autocomplete="off"
+                placeholder={props.input_placeholder}
+                value={(*query_handle).clone()}
+                {oninput}
+                {onkeydown}
+                {onfocus}
+                {onblur}
+            />
+            if open {
+                <ul class={props.listbox_class} role="listbox" id={listbox_id} aria-label={props.label}>
+                    if matches.is_empty() {
+                        <li class={props.option_class} aria-disabled="true">{ props.no_results_text }</li>
+                    }
+                    { for matches.iter().enumerate().map(|(index, option)| {
+                        let option = *option;
+                        // Selecting on mousedown keeps the focus, which would otherwise close the list first
+                        let onmousedown = {
+                            let select = select.clone();
+                            Callback::from(move |event: MouseEvent| {
+                                event.prevent_default();
+                                select.emit(option);
+                            })
+                        };
+                        html! {
+                            <li
+                                id={option_id(index)}
+                                class={classes!(props.option_class, (index == active).then_some(props.option_active_class))}
+                                role="option"
+                                aria-selected={(option.0 == props.value_handle.as_str()).to_string()}
+                                {onmousedown}
+                            >
+                                { option.1 }
+                            </li>
+                        }
+                    }) }
+                </ul>
+            }
+        </div>
+    }
+}
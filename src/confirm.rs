@@ -0,0 +1,182 @@
+use crate::CustomInput;
+use yew::prelude::*;
+
+/// Props for the confirmation input.
+#[derive(Properties, PartialEq, Clone)]
+pub struct ConfirmInputProps {
+    /// The type of both inputs, e.g. "password" or "email".
+    #[prop_or("password")]
+    pub input_type: &'static str,
+
+    /// The name attribute of the first input.
+    pub name: &'static str,
+
+    /// The name attribute of the confirmation input.
+    #[prop_or("confirm")]
+    pub confirm_name: &'static str,
+
+    /// The label of the first input.
+    #[prop_or_default]
+    pub label: &'static str,
+
+    /// The label of the confirmation input.
+    #[prop_or_default]
+    pub confirm_label: &'static str,
+
+    /// The placeholder of the first input.
+    #[prop_or_default]
+    pub input_placeholder: &'static str,
+
+    /// The placeholder of the confirmation input.
+    #[prop_or_default]
+    pub confirm_placeholder: &'static str,
+
+    /// Whether both inputs are required.
+    #[prop_or_default]
+    pub required: bool,
+
+    /// The state handle for managing the value of the first input.
+    pub value_handle: UseStateHandle<String>,
+
+    /// The state handle for managing the value of the confirmation input.
+    pub confirm_handle: UseStateHandle<String>,
+
+    /// The state handle receiving the combined validity: the first input is valid and both values match.
+    pub valid_handle: UseStateHandle<bool>,
+
+    /// A callback function to validate the value of the first input.
+    pub validate_function: Callback<String, bool>,
+
+    /// The error message displayed below the first input when it is invalid.
+    #[prop_or_default]
+    pub error_message: &'static str,
+
+    /// The error message displayed below the confirmation input when the values don't match.
+    #[prop_or("The values don't match")]
+    pub mismatch_message: &'static str,
+
+    /// The CSS class to be applied to the container of each input.
+    #[prop_or_default]
+    pub form_input_class: &'static str,
+
+    /// The CSS class to be applied to the field of each input.
+    #[prop_or_default]
+    pub form_input_field_class: &'static str,
+
+    /// The CSS class to be applied to the label of each input.
+    #[prop_or_default]
+    pub form_input_label_class: &'static str,
+
+    /// The CSS class to be applied to each input.
+    #[prop_or_default]
+    pub form_input_input_class: &'static str,
+
+    /// The CSS class to be applied to the error message of each input.
+    #[prop_or_default]
+    pub form_input_error_class: &'static str,
+}
+
+/// custom_confirm_input
+/// Two linked `CustomInput`s whose values must match, e.g. a password and its confirmation.
+///
+/// The mismatch error is only displayed below the confirmation input, once the user has typed in it, and
+/// stays while the values differ, even if the confirmation is cleared.
+///
+/// # Examples
+/// ```
+/// use input_yew::CustomConfirmInput;
+/// use yew::prelude::*;
+///
+/// #[function_component(NewPassword)]
+/// fn new_password() -> Html {
+///     let password_handle = use_state(String::default);
+///     let confirm_handle = use_state(String::default);
+///     let valid_handle = use_state(|| false);
+///
+///     html! {
+///         <CustomConfirmInput
+///           name={"password"}
+///           label={"Password"}
+///           confirm_label={"Confirm password"}
+///           value_handle={password_handle}
+///           confirm_handle={confirm_handle}
+///           valid_handle={valid_handle}
+///           validate_function={|password: String| password.len() >= 8}
+///           error_message={"Use at least 8 characters"}
+///           mismatch_message={"Passwords don't match"}
+///         />
+///     }
+/// }
+/// ```
+#[function_component(CustomConfirmInput)]
+pub fn custom_confirm_input(props: &ConfirmInputProps) -> Html {
+    let value_ref = use_node_ref();
+    let confirm_ref = use_node_ref();
+    let value_valid_handle = use_state(|| true);
+    let confirm_valid_handle = use_state(|| true);
+    let confirm_touched_handle = use_state_eq(|| false);
+
+    let matches = *props.value_handle == *props.confirm_handle;
+    let confirm_touched = *confirm_touched_handle;
+
+    {
+        let valid_handle = props.valid_handle.clone();
+        // An optional pair left blank is valid, as both values match
+        let value_valid =
+            *value_valid_handle && (!props.required || !props.value_handle.is_empty());
+        use_effect_with((value_valid, matches), move |(value_valid, matches)| {
+            valid_handle.set(*value_valid && *matches);
+        });
+    }
+
+    let confirm_validate = {
+        let value = (*props.value_handle).clone();
+        Callback::from(move |confirm: String| confirm == value)
+    };
+
+    // Fired once the user edits the confirmation, unlike a check on its value that clearing it would reset
+    let on_confirm_change = Callback::from(move |_: (&'static str, String, bool)| {
+        confirm_touched_handle.set(true);
+    });
+
+    html! {
+        <>
+            <CustomInput
+                input_type={props.input_type}
+                name={props.name}
+                label={props.label}
+                input_placeholder={props.input_placeholder}
+                required={props.required}
+                input_ref={value_ref}
+                input_handle={props.value_handle.clone()}
+                input_valid_handle={value_valid_handle}
+                validate_function={props.validate_function.clone()}
+                error_message={props.error_message}
+                form_input_class={props.form_input_class}
+                form_input_field_class={props.form_input_field_class}
+                form_input_label_class={props.form_input_label_class}
+                form_input_input_class={props.form_input_input_class}
+                form_input_error_class={props.form_input_error_class}
+            />
+            <CustomInput
+                input_type={props.input_type}
+                name={props.confirm_name}
+                label={props.confirm_label}
+                input_placeholder={props.confirm_placeholder}
+                required={props.required}
+                input_ref={confirm_ref}
+                input_handle={props.confirm_handle.clone()}
+                input_valid_handle={confirm_valid_handle}
+                validate_function={confirm_validate}
+                error_message={props.mismatch_message}
+                show_error={Some(confirm_touched && !matches)}
+                on_named_change={on_confirm_change}
+                form_input_class={props.form_input_class}
+                form_input_field_class={props.form_input_field_class}
+                form_input_label_class={props.form_input_label_class}
+                form_input_input_class={props.form_input_input_class}
+                form_input_error_class={props.form_input_error_class}
+            />
+        </>
+    }
+}
@@ -0,0 +1,470 @@
+use crate::VISUALLY_HIDDEN_STYLE;
+use gloo_timers::callback::Timeout;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+/// The progress of a count-up animation from `start` to `end`.
+///
+/// The elapsed time is tracked explicitly rather than derived from the start time, so that pausing freezes
+/// the value and resuming continues from it without skipping ahead or overshooting `end`. Timestamps are
+/// in milliseconds, e.g. from `performance.now()` or `requestAnimationFrame`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CountUpAnimation {
+    /// The value the animation starts from.
+    pub start: f64,
+    /// The value the animation ends at.
+    pub end: f64,
+    /// The duration of the animation, in milliseconds, not counting pauses.
+    pub duration_ms: f64,
+    /// Whether the value slows down towards `end` instead of changing linearly.
+    pub use_easing: bool,
+    /// The time spent animating before the last pause.
+    elapsed_ms: f64,
+    /// The timestamp the animation was last started or resumed at, or `None` while paused.
+    running_since: Option<f64>,
+}
+
+impl CountUpAnimation {
+    /// Returns a paused animation that hasn't started yet.
+    pub fn new(start: f64, end: f64, duration_ms: f64, use_easing: bool) -> Self {
+        Self {
+            start,
+            end,
+            duration_ms,
+            use_easing,
+            elapsed_ms: 0.0,
+            running_since: None,
+        }
+    }
+
+    /// Starts or resumes the animation at `now`. Does nothing while it's running.
+    pub fn resume(&mut self, now: f64) {
+        if self.running_since.is_none() {
+            self.running_since = Some(now);
+        }
+    }
+
+    /// Pauses the animation at `now`, keeping its progress. Does nothing while it's paused.
+    pub fn pause(&mut self, now: f64) {
+        if let Some(since) = self.running_since.take() {
+            self.elapsed_ms += (now - since).max(0.0);
+        }
+    }
+
+    /// Returns `true` while the animation is running, including after it completed until it's paused.
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    /// Returns `true` when the animation hasn't made any progress yet.
+    pub fn is_pristine(&self) -> bool {
+        self.elapsed_ms == 0.0 && self.running_since.is_none()
+    }
+
+    /// Returns the time spent animating at `now`, not counting pauses, up to the duration.
+    pub fn elapsed(&self, now: f64) -> f64 {
+        let running = self
+            .running_since
+            .map_or(0.0, |since| (now - since).max(0.0));
+        (self.elapsed_ms + running).min(self.duration_ms)
+    }
+
+    /// Returns `true` once the animation reached `end`.
+    pub fn is_complete(&self, now: f64) -> bool {
+        self.elapsed(now) >= self.duration_ms
+    }
+
+    /// value_at
+    /// Returns the value of the animation at `now`.
+    ///
+    /// # Arguments
+    /// * `now` - The current timestamp, in milliseconds.
+    ///
+    /// # Returns
+    /// (f64): The animated value, exactly `end` once the animation completed.
+    ///
+    /// # Examples
+    /// ```
+    /// use input_yew::count::CountUpAnimation;
+    ///
+    /// let mut animation = CountUpAnimation::new(0.0, 100.0, 1000.0, false);
+    /// animation.resume(0.0);
+    /// // Pausing at 50% freezes the value
+    /// animation.pause(500.0);
+    /// assert_eq!(animation.value_at(500.0), 50.0);
+    /// assert_eq!(animation.value_at(3000.0), 50.0);
+    ///
+    /// // Resuming continues from the paused value rather than jumping ahead
+    /// animation.resume(3000.0);
+    /// assert_eq!(animation.value_at(3000.0), 50.0);
+    /// assert_eq!(animation.value_at(3250.0), 75.0);
+    /// assert!(!animation.is_complete(3250.0));
+    ///
+    /// // Late frames don't overshoot the end
+    /// assert_eq!(animation.value_at(3500.0), 100.0);
+    /// assert_eq!(animation.value_at(9000.0), 100.0);
+    /// assert!(animation.is_complete(3500.0));
+    /// ```
+    pub fn value_at(&self, now: f64) -> f64 {
+        if self.duration_ms <= 0.0 || self.is_complete(now) {
+            return self.end;
+        }
+        let progress = self.elapsed(now) / self.duration_ms;
+        // easeOutExpo, rescaled to reach exactly 1 at the end
+        let eased = if self.use_easing {
+            (1.0 - 2f64.powf(-10.0 * progress)) / (1.0 - 2f64.powf(-10.0))
+        } else {
+            progress
+        };
+        self.start + (self.end - self.start) * eased
+    }
+}
+
+/// format_count
+/// Formats a number with a fixed number of decimal places, grouping the digits of its integer part by
+/// thousands.
+///
+/// # Arguments
+/// * `value` - The number to format.
+/// * `decimal_places` - The number of decimal places.
+/// * `separator` - The separator between groups of thousands, or "" to not group the digits.
+/// * `decimal` - The decimal separator.
+///
+/// # Returns
+/// (String): The formatted number.
+///
+/// # Examples
+/// ```
+/// use input_yew::count::format_count;
+///
+/// assert_eq!(format_count(1234567.891, 2, ",", "."), "1,234,567.89");
+/// assert_eq!(format_count(-1234.6, 0, " ", ","), "-1 235");
+/// assert_eq!(format_count(999.0, 1, "", "."), "999.0");
+/// ```
+pub fn format_count(value: f64, decimal_places: usize, separator: &str, decimal: &str) -> String {
+    let formatted = format!("{:.*}", decimal_places, value.abs());
+    let (integer, fraction) = match formatted.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (formatted.as_str(), None),
+    };
+
+    let mut grouped = String::new();
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            grouped.push_str(separator);
+        }
+        grouped.push(digit);
+    }
+
+    let sign = if value < 0.0 && formatted.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        "-"
+    } else {
+        ""
+    };
+    match fraction {
+        Some(fraction) => format!("{}{}{}{}", sign, grouped, decimal, fraction),
+        None => format!("{}{}", sign, grouped),
+    }
+}
+
+/// The callback of a frame, receiving its timestamp.
+type FrameCallback = Rc<RefCell<Option<Closure<dyn FnMut(f64)>>>>;
+
+/// A `requestAnimationFrame` loop calling its callback with the frame timestamp until it returns `false`.
+/// The pending frame is cancelled when the loop is dropped.
+struct FrameLoop {
+    handle: Rc<Cell<Option<i32>>>,
+    closure: FrameCallback,
+}
+
+fn request_frame(closure: &Closure<dyn FnMut(f64)>) -> Option<i32> {
+    web_sys::window()?
+        .request_animation_frame(closure.as_ref().unchecked_ref())
+        .ok()
+}
+
+impl FrameLoop {
+    fn start(mut on_frame: impl FnMut(f64) -> bool + 'static) -> Self {
+        let handle = Rc::new(Cell::new(None));
+        let closure: FrameCallback = Rc::new(RefCell::new(None));
+        {
+            let handle = handle.clone();
+            let next = closure.clone();
+            *closure.borrow_mut() = Some(Closure::new(move |timestamp: f64| {
+                handle.set(None);
+                if on_frame(timestamp) {
+                    if let Some(next) = next.borrow().as_ref() {
+                        handle.set(request_frame(next));
+                    }
+                }
+            }));
+        }
+        if let Some(first) = closure.borrow().as_ref() {
+            handle.set(request_frame(first));
+        }
+        Self { handle, closure }
+    }
+}
+
+impl Drop for FrameLoop {
+    fn drop(&mut self) {
+        if let (Some(window), Some(handle)) = (web_sys::window(), self.handle.take()) {
+            let _ = window.cancel_animation_frame(handle);
+        }
+        // The closure holds a reference to itself to schedule the next frame
+        self.closure.borrow_mut().take();
+    }
+}
+
+/// Returns the current timestamp, on the same clock as `requestAnimationFrame`.
+fn now() -> f64 {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map_or(0.0, |performance| performance.now())
+}
+
+/// Props for the count-up component.
+#[derive(Properties, PartialEq, Clone)]
+pub struct CountUpProps {
+    /// The value to count up, or down, to.
+    pub end: f64,
+
+    /// The value to count from.
+    #[prop_or_default]
+    pub start: f64,
+
+    /// The duration of the animation, in milliseconds, not counting pauses.
+    #[prop_or(2000)]
+    pub duration_ms: u32,
+
+    /// Whether the value slows down towards `end` instead of changing linearly.
+    #[prop_or(true)]
+    pub use_easing: bool,
+
+    /// The number of decimal places displayed.
+    #[prop_or_default]
+    pub decimal_places: usize,
+
+    /// The separator between groups of thousands, or "" to not group the digits.
+    #[prop_or(",")]
+    pub separator: &'static str,
+
+    /// The decimal separator.
+    #[prop_or(".")]
+    pub decimal: &'static str,
+
+    /// Text displayed before the value, e.g. "$".
+    #[prop_or_default]
+    pub prefix: &'static str,
+
+    /// Text displayed after the value, e.g. "%".
+    #[prop_or_default]
+    pub suffix: &'static str,
+
+    /// A delay, in milliseconds, before the animation starts, e.g. to wait for other animations of the page.
+    /// Pausing during the delay cancels it, and it starts over on resume.
+    #[prop_or_default]
+    pub start_delay_ms: u32,
+
+    /// The maximum number of value updates per second, e.g. `Some(30)` for pages with many counters. Every
+    /// animation frame updates the value when `None`. The final value is always displayed.
+    #[prop_or_default]
+    pub fps_cap: Option<u32>,
+
+    /// Whether the animation is paused. Resuming continues from the displayed value.
+    #[prop_or_default]
+    pub paused: bool,
+
+    /// A counter to bump, e.g. from a `UseStateHandle<u32>`, to restart the animation from `start`. The
+    /// initial `0` restarts nothing.
+    #[prop_or_default]
+    pub reset: u32,
+
+    /// A callback invoked when the animation starts.
+    #[prop_or_default]
+    pub on_start: Callback<()>,
+
+    /// A callback invoked when the animation reaches `end`.
+    #[prop_or_default]
+    pub on_complete: Callback<()>,
+
+    /// The text announced to screen readers once the animation completes, where `{value}` stands for the
+    /// displayed value, e.g. "{value} downloads so far". The animated value is hidden from screen readers
+    /// until then.
+    #[prop_or("{value}")]
+    pub announcement: &'static str,
+
+    /// The CSS class to be applied to the value.
+    #[prop_or_default]
+    pub class: &'static str,
+}
+
+/// custom_count_up
+/// A number animated from `start` to `end`, e.g. for statistics revealed on a landing page.
+///
+/// The animation runs on `requestAnimationFrame` and can be paused and resumed through the `paused` prop,
+/// continuing from the displayed value. Changing `start`, `end` or `duration_ms`, or bumping `reset`,
+/// restarts it.
+///
+/// # Examples
+/// ```
+/// use input_yew::CustomCountUp;
+/// use yew::prelude::*;
+///
+/// #[function_component(Downloads)]
+/// fn downloads() -> Html {
+///     let paused_handle = use_state(|| false);
+///     let on_toggle = {
+///         let paused_handle = paused_handle.clone();
+///         Callback::from(move |_: MouseEvent| paused_handle.set(!*paused_handle))
+///     };
+///
+///     html! {
+///         <>
+///             <CustomCountUp end={1_250_000.0} suffix={" downloads"} paused={*paused_handle} />
+///             <button onclick={on_toggle}>{ if *paused_handle { "Resume" } else { "Pause" } }</button>
+///         </>
+///     }
+/// }
+/// ```
+#[function_component(CustomCountUp)]
+pub fn custom_count_up(props: &CountUpProps) -> Html {
+    let animation_params = (
+        props.start,
+        props.end,
+        props.duration_ms,
+        props.use_easing,
+        props.reset,
+    );
+    let animation = use_mut_ref(|| {
+        CountUpAnimation::new(
+            props.start,
+            props.end,
+            props.duration_ms as f64,
+            props.use_easing,
+        )
+    });
+    let value_handle = use_state(|| props.start);
+    // The final value is announced once, rather than every frame
+    let completed_handle = use_state(|| false);
+
+    {
+        let animation = animation.clone();
+        let value_handle = value_handle.clone();
+        let completed_handle = completed_handle.clone();
+        use_effect_with(
+            animation_params,
+            move |(start, end, duration_ms, use_easing, _)| {
+                let restarted =
+                    CountUpAnimation::new(*start, *end, *duration_ms as f64, *use_easing);
+                if *animation.borrow() != restarted {
+                    *animation.borrow_mut() = restarted;
+                    value_handle.set(*start);
+                    completed_handle.set(false);
+                }
+            },
+        );
+    }
+
+    {
+        let animation = animation.clone();
+        let value_handle = value_handle.clone();
+        let completed_handle = completed_handle.clone();
+        let on_start = props.on_start.clone();
+        let on_complete = props.on_complete.clone();
+        // Declared after the restart above, so that a restarted animation runs from its beginning
+        use_effect_with(
+            (
+                props.paused,
+                props.start_delay_ms,
+                props.fps_cap,
+                animation_params,
+            ),
+            move |(paused, start_delay_ms, fps_cap, _)| {
+                let pristine = animation.borrow().is_pristine();
+                let frames: Rc<RefCell<Option<FrameLoop>>> = Rc::default();
+                let min_frame_interval = fps_cap
+                    .filter(|fps| *fps > 0)
+                    .map(|fps| 1000.0 / fps as f64);
+                let run = {
+                    let animation = animation.clone();
+                    let frames = frames.clone();
+                    move || {
+                        if pristine {
+                            on_start.emit(());
+                        }
+                        let now = now();
+                        if animation.borrow().is_complete(now) {
+                            // Only an animation without duration completes as it starts
+                            if pristine {
+                                value_handle.set(animation.borrow().end);
+                                completed_handle.set(true);
+                                on_complete.emit(());
+                            }
+                            return;
+                        }
+                        animation.borrow_mut().resume(now);
+                        let mut last_frame = None;
+                        *frames.borrow_mut() = Some(FrameLoop::start(move |timestamp| {
+                            let animation = *animation.borrow();
+                            let complete = animation.is_complete(timestamp);
+                            // Frames arriving too soon are skipped, but never the last one printing `end`
+                            let too_soon = matches!(
+                                (min_frame_interval, last_frame),
+                                (Some(interval), Some(last)) if timestamp - last < interval
+                            );
+                            if too_soon && !complete {
+                                return true;
+                            }
+                            last_frame = Some(timestamp);
+                            value_handle.set(animation.value_at(timestamp));
+                            if complete {
+                                completed_handle.set(true);
+                                on_complete.emit(());
+                            }
+                            !complete
+                        }));
+                    }
+                };
+
+                // Pausing or restarting during the delay cancels it, and it starts over on resume
+                let delay = if *paused {
+                    animation.borrow_mut().pause(now());
+                    None
+                } else if pristine && *start_delay_ms > 0 {
+                    Some(Timeout::new(*start_delay_ms, run))
+                } else {
+                    run();
+                    None
+                };
+                move || {
+                    drop(delay);
+                    frames.borrow_mut().take();
+                }
+            },
+        );
+    }
+
+    let value = format_count(
+        *value_handle,
+        props.decimal_places,
+        props.separator,
+        props.decimal,
+    );
+    let text = format!("{}{}{}", props.prefix, value, props.suffix);
+
+    html! {
+        <span class={props.class}>
+            // The changing text is only exposed to screen readers once it settles
+            <span aria-hidden={(!*completed_handle).then_some("true")}>{ text.clone() }</span>
+            <span role="status" aria-live="polite" style={VISUALLY_HIDDEN_STYLE}>
+                if *completed_handle {
+                    { props.announcement.replace("{value}", &text) }
+                }
+            </span>
+        </span>
+    }
+}
@@ -1,1970 +1,2145 @@
-pub static COUNTRY_CODES: [(&str, &str, &str, &str, &str, &str); 246] = [
-    (
-        "+93",
-        "\u{1F1E6}\u{1F1EB}",
-        "+93 ...-....",
-        "Afghanistan",
-        "Asia",
-        "Southern Asia",
-    ),
-    (
-        "+355",
-        "\u{1F1E6}\u{1F1F1}",
-        "+355 ... ....",
-        "Albania",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+213",
-        "\u{1F1E9}\u{1F1FF}",
-        "+213 ... .. ..",
-        "Algeria",
-        "Africa",
-        "Northern Africa",
-    ),
-    (
-        "+376",
-        "\u{1F1E6}\u{1F1E9}",
-        "+376 ... ...",
-        "Andorra",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+244",
-        "\u{1F1E6}\u{1F1F4}",
-        "+244 ... ....",
-        "Angola",
-        "Africa",
-        "Middle Africa",
-    ),
-    (
-        "+1264",
-        "\u{1F1E6}\u{1F1EE}",
-        "+1264 ... ....",
-        "Anguilla",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+1268",
-        "\u{1F1E6}\u{1F1EC}",
-        "+1268 ... ....",
-        "Antigua and Barbuda",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+54",
-        "\u{1F1E6}\u{1F1F7}",
-        "+54 ... .......",
-        "Argentina",
-        "America",
-        "South America",
-    ),
-    (
-        "+374",
-        "\u{1F1E6}\u{1F1F2}",
-        "+374 ... ....",
-        "Armenia",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+297",
-        "\u{1F1E6}\u{1F1FC}",
-        "+297 ... ....",
-        "Aruba",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+247",
-        "\u{1F1E6}\u{1F1F8}",
-        "+247 ...-....",
-        "Ascension Island",
-        "Africa",
-        "Atlantic",
-    ),
-    (
-        "+61",
-        "\u{1F1E6}\u{1F1FA}",
-        "+61 .. ... ...",
-        "Australia",
-        "Oceania",
-        "Australia",
-    ),
-    (
-        "+672",
-        "\u{1F1E6}\u{1F1FA}",
-        "+672 .. ... ...",
-        "Australian External Territories",
-        "Oceania",
-        "Australia",
-    ),
-    (
-        "+43",
-        "\u{1F1E6}\u{1F1F9}",
-        "+43 ... .......",
-        "Austria",
-        "Europe",
-        "Western Europe",
-    ),
-    (
-        "+994",
-        "\u{1F1E6}\u{1F1FF}",
-        "+994 ... .. ..",
-        "Azerbaijan",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+1242",
-        "\u{1F1E7}\u{1F1F8}",
-        "+1242 ... ....",
-        "Bahamas",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+973",
-        "\u{1F1E7}\u{1F1ED}",
-        "+973 ... ....",
-        "Bahrain",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+880",
-        "\u{1F1E7}\u{1F1E9}",
-        "+880 ...-.....",
-        "Bangladesh",
-        "Asia",
-        "Southern Asia",
-    ),
-    (
-        "+1246",
-        "\u{1F1E7}\u{1F1E7}",
-        "+1246 ... ....",
-        "Barbados",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+375",
-        "\u{1F1E7}\u{1F1FE}",
-        "+375 ... ....",
-        "Belarus",
-        "Europe",
-        "Eastern Europe",
-    ),
-    (
-        "+32",
-        "\u{1F1E7}\u{1F1EA}",
-        "+32 .. ... ..",
-        "Belgium",
-        "Europe",
-        "Western Europe",
-    ),
-    (
-        "+501",
-        "\u{1F1E7}\u{1F1FF}",
-        "+501 ...-....",
-        "Belize",
-        "America",
-        "Central America",
-    ),
-    (
-        "+229",
-        "\u{1F1E7}\u{1F1EF}",
-        "+229 ... ....",
-        "Benin",
-        "Africa",
-        "Western Africa",
-    ),
-    (
-        "+1441",
-        "\u{1F1E7}\u{1F1F2}",
-        "+1441 ... ....",
-        "Bermuda",
-        "America",
-        "Northern America",
-    ),
-    (
-        "+975",
-        "\u{1F1E7}\u{1F1F9}",
-        "+975 ... ....",
-        "Bhutan",
-        "Asia",
-        "Southern Asia",
-    ),
-    (
-        "+591",
-        "\u{1F1E7}\u{1F1F4}",
-        "+591 ... ....",
-        "Bolivia",
-        "America",
-        "South America",
-    ),
-    (
-        "+599",
-        "\u{1F1E7}\u{1F1F6}",
-        "+599 ... ....",
-        "Bonaire",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+387",
-        "\u{1F1E7}\u{1F1E6}",
-        "+387 ... ....",
-        "Bosnia and Herzegovina",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+267",
-        "\u{1F1E7}\u{1F1FC}",
-        "+267 ... ....",
-        "Botswana",
-        "Africa",
-        "Southern Africa",
-    ),
-    (
-        "+55",
-        "\u{1F1E7}\u{1F1F7}",
-        "+55 .. .......",
-        "Brazil",
-        "America",
-        "South America",
-    ),
-    (
-        "+246",
-        "\u{1F1EE}\u{1F1F4}",
-        "+246 ...-....",
-        "British Indian Ocean Territory",
-        "Africa",
-        "Indian Ocean",
-    ),
-    (
-        "+1284",
-        "\u{1F1FB}\u{1F1EC}",
-        "+1284 ... ....",
-        "British Virgin Islands",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+673",
-        "\u{1F1E7}\u{1F1F3}",
-        "+673 ... ....",
-        "Brunei",
-        "Asia",
-        "South-Eastern Asia",
-    ),
-    (
-        "+359",
-        "\u{1F1E7}\u{1F1EC}",
-        "+359 ... ....",
-        "Bulgaria",
-        "Europe",
-        "Eastern Europe",
-    ),
-    (
-        "+226",
-        "\u{1F1E7}\u{1F1EB}",
-        "+226 ... ....",
-        "Burkina Faso",
-        "Africa",
-        "Western Africa",
-    ),
-    (
-        "+257",
-        "\u{1F1E7}\u{1F1EE}",
-        "+257 ... ....",
-        "Burundi",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+855",
-        "\u{1F1F0}\u{1F1ED}",
-        "+855 ...-....",
-        "Cambodia",
-        "Asia",
-        "South-Eastern Asia",
-    ),
-    (
-        "+237",
-        "\u{1F1E8}\u{1F1F2}",
-        "+237 ... .. ..",
-        "Cameroon",
-        "Africa",
-        "Middle Africa",
-    ),
-    (
-        "+1",
-        "\u{1F1E8}\u{1F1E6}",
-        "+1 ... ... ....",
-        "Canada",
-        "America",
-        "Northern America",
-    ),
-    (
-        "+238",
-        "\u{1F1E8}\u{1F1FB}",
-        "+238 ... ....",
-        "Cape Verde",
-        "Africa",
-        "Western Africa",
-    ),
-    (
-        "+599",
-        "\u{1F1E7}\u{1F1F6}",
-        "+599 ... ....",
-        "Caribbean Netherlands",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+1345",
-        "\u{1F1F0}\u{1F1FE}",
-        "+1345 ... ....",
-        "Cayman Islands",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+236",
-        "\u{1F1E8}\u{1F1EB}",
-        "+236 ... .. ..",
-        "Central African Republic",
-        "Africa",
-        "Middle Africa",
-    ),
-    (
-        "+235",
-        "\u{1F1F9}\u{1F1E9}",
-        "+235 ... ....",
-        "Chad",
-        "Africa",
-        "Middle Africa",
-    ),
-    (
-        "+56",
-        "\u{1F1E8}\u{1F1F1}",
-        "+56 ... .......",
-        "Chile",
-        "America",
-        "South America",
-    ),
-    (
-        "+86",
-        "\u{1F1E8}\u{1F1F3}",
-        "+86 .. .... ....",
-        "China",
-        "Asia",
-        "Eastern Asia",
-    ),
-    (
-        "+61",
-        "\u{1F1E8}\u{1F1FD}",
-        "+61 .. ... ...",
-        "Christmas Island",
-        "Oceania",
-        "Australia",
-    ),
-    (
-        "+61",
-        "\u{1F1E8}\u{1F1E8}",
-        "+61 .. ... ...",
-        "Cocos Islands",
-        "Oceania",
-        "Australia",
-    ),
-    (
-        "+57",
-        "\u{1F1E8}\u{1F1F4}",
-        "+57 ... .......",
-        "Colombia",
-        "America",
-        "South America",
-    ),
-    (
-        "+269",
-        "\u{1F1F0}\u{1F1F2}",
-        "+269 ... ....",
-        "Comoros",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+242",
-        "\u{1F1E8}\u{1F1EC}",
-        "+242 ... .. ..",
-        "Congo",
-        "Africa",
-        "Middle Africa",
-    ),
-    (
-        "+243",
-        "\u{1F1E8}\u{1F1E9}",
-        "+243 ... ......",
-        "Congo (DRC)",
-        "Africa",
-        "Middle Africa",
-    ),
-    (
-        "+682",
-        "\u{1F1E8}\u{1F1F0}",
-        "+682 ... ....",
-        "Cook Islands",
-        "Oceania",
-        "Polynesia",
-    ),
-    (
-        "+506",
-        "\u{1F1E8}\u{1F1F7}",
-        "+506 ... ....",
-        "Costa Rica",
-        "America",
-        "Central America",
-    ),
-    (
-        "+225",
-        "\u{1F1E8}\u{1F1EE}",
-        "+225 ... .. ..",
-        "C\u{F4}te d'Ivoire",
-        "Africa",
-        "Western Africa",
-    ),
-    (
-        "+385",
-        "\u{1F1ED}\u{1F1F7}",
-        "+385 ... ....",
-        "Croatia",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+53",
-        "\u{1F1E8}\u{1F1FA}",
-        "+53 ... .......",
-        "Cuba",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+599",
-        "\u{1F1E7}\u{1F1F6}",
-        "+599 ... ....",
-        "Cura\u{E7}ao",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+357",
-        "\u{1F1E8}\u{1F1FE}",
-        "+357 ... ....",
-        "Cyprus",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+420",
-        "\u{1F1E8}\u{1F1FF}",
-        "+420 ... ....",
-        "Czech Republic",
-        "Europe",
-        "Eastern Europe",
-    ),
-    (
-        "+45",
-        "\u{1F1E9}\u{1F1F0}",
-        "+45 .. .. .. ..",
-        "Denmark",
-        "Europe",
-        "Northern Europe",
-    ),
-    (
-        "+246",
-        "\u{1F1E9}\u{1F1EC}",
-        "+246 ... ....",
-        "Diego Garcia",
-        "Africa",
-        "Indian Ocean",
-    ),
-    (
-        "+253",
-        "\u{1F1E9}\u{1F1EF}",
-        "+253 ... ....",
-        "Djibouti",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+1767",
-        "\u{1F1E9}\u{1F1F2}",
-        "+1767 ... ....",
-        "Dominica",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+1",
-        "\u{1F1E9}\u{1F1F4}",
-        "+1 ... ... ....",
-        "Dominican Republic",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+593",
-        "\u{1F1EA}\u{1F1E8}",
-        "+593 ... ....",
-        "Ecuador",
-        "America",
-        "South America",
-    ),
-    (
-        "+20",
-        "\u{1F1EA}\u{1F1EC}",
-        "+20 ... .......",
-        "Egypt",
-        "Africa",
-        "Northern Africa",
-    ),
-    (
-        "+503",
-        "\u{1F1F8}\u{1F1FB}",
-        "+503 ... ....",
-        "El Salvador",
-        "America",
-        "Central America",
-    ),
-    (
-        "+240",
-        "\u{1F1EC}\u{1F1F6}",
-        "+240 ... ....",
-        "Equatorial Guinea",
-        "Africa",
-        "Middle Africa",
-    ),
-    (
-        "+291",
-        "\u{1F1EA}\u{1F1F7}",
-        "+291 ... ....",
-        "Eritrea",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+372",
-        "\u{1F1EA}\u{1F1EA}",
-        "+372 ... ....",
-        "Estonia",
-        "Europe",
-        "Northern Europe",
-    ),
-    (
-        "+251",
-        "\u{1F1EA}\u{1F1F9}",
-        "+251 ... ....",
-        "Ethiopia",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+500",
-        "\u{1F1EB}\u{1F1F0}",
-        "+500 ... ....",
-        "Falkland Islands",
-        "America",
-        "South America",
-    ),
-    (
-        "+298",
-        "\u{1F1EB}\u{1F1F4}",
-        "+298 ... ....",
-        "Faroe Islands",
-        "Europe",
-        "Northern Europe",
-    ),
-    (
-        "+679",
-        "\u{1F1EB}\u{1F1EF}",
-        "+679 ... ....",
-        "Fiji",
-        "Oceania",
-        "Melanesia",
-    ),
-    (
-        "+358",
-        "\u{1F1EB}\u{1F1EE}",
-        "+358 ... .. ..",
-        "Finland",
-        "Europe",
-        "Northern Europe",
-    ),
-    (
-        "+33",
-        "\u{1F1EB}\u{1F1F7}",
-        "+33 .. .. .. ..",
-        "France",
-        "Europe",
-        "Western Europe",
-    ),
-    (
-        "+596",
-        "\u{1F1F2}\u{1F1EB}",
-        "+596 ... ....",
-        "French Antilles",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+594",
-        "\u{1F1EC}\u{1F1EB}",
-        "+594 ... ....",
-        "French Guiana",
-        "America",
-        "South America",
-    ),
-    (
-        "+689",
-        "\u{1F1F5}\u{1F1EB}",
-        "+689 ... ....",
-        "French Polynesia",
-        "Oceania",
-        "Polynesia",
-    ),
-    (
-        "+241",
-        "\u{1F1EC}\u{1F1E6}",
-        "+241 ... ....",
-        "Gabon",
-        "Africa",
-        "Middle Africa",
-    ),
-    (
-        "+220",
-        "\u{1F1EC}\u{1F1F2}",
-        "+220 ... ....",
-        "Gambia",
-        "Africa",
-        "Western Africa",
-    ),
-    (
-        "+995",
-        "\u{1F1EC}\u{1F1EA}",
-        "+995 ... ....",
-        "Georgia",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+49",
-        "\u{1F1E9}\u{1F1EA}",
-        "+49 .. ... ...",
-        "Germany",
-        "Europe",
-        "Western Europe",
-    ),
-    (
-        "+233",
-        "\u{1F1EC}\u{1F1ED}",
-        "+233 ... ....",
-        "Ghana",
-        "Africa",
-        "Western Africa",
-    ),
-    (
-        "+350",
-        "\u{1F1EC}\u{1F1EE}",
-        "+350 ... ....",
-        "Gibraltar",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+30",
-        "\u{1F1EC}\u{1F1F7}",
-        "+30 .. ... ....",
-        "Greece",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+299",
-        "\u{1F1EC}\u{1F1F1}",
-        "+299 ... ....",
-        "Greenland",
-        "America",
-        "Northern America",
-    ),
-    (
-        "+1473",
-        "\u{1F1EC}\u{1F1E9}",
-        "+1473 ... ....",
-        "Grenada",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+590",
-        "\u{1F1EC}\u{1F1F5}",
-        "+590 ... ....",
-        "Guadeloupe",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+1671",
-        "\u{1F1EC}\u{1F1FA}",
-        "+1671 ... ....",
-        "Guam",
-        "Oceania",
-        "Micronesia",
-    ),
-    (
-        "+502",
-        "\u{1F1EC}\u{1F1F9}",
-        "+502 ... ....",
-        "Guatemala",
-        "America",
-        "Central America",
-    ),
-    (
-        "+44",
-        "\u{1F1EC}\u{1F1EC}",
-        "+44 .. .... ..",
-        "Guernsey",
-        "Europe",
-        "Northern Europe",
-    ),
-    (
-        "+224",
-        "\u{1F1EC}\u{1F1F3}",
-        "+224 ... ....",
-        "Guinea",
-        "Africa",
-        "Western Africa",
-    ),
-    (
-        "+245",
-        "\u{1F1EC}\u{1F1FC}",
-        "+245 ... ....",
-        "Guinea-Bissau",
-        "Africa",
-        "Western Africa",
-    ),
-    (
-        "+592",
-        "\u{1F1EC}\u{1F1FE}",
-        "+592 ... ....",
-        "Guyana",
-        "America",
-        "South America",
-    ),
-    (
-        "+509",
-        "\u{1F1ED}\u{1F1F9}",
-        "+509 ... ....",
-        "Haiti",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+504",
-        "\u{1F1ED}\u{1F1F3}",
-        "+504 ... ....",
-        "Honduras",
-        "America",
-        "Central America",
-    ),
-    (
-        "+852",
-        "\u{1F1ED}\u{1F1F0}",
-        "+852 ... ....",
-        "Hong Kong",
-        "Asia",
-        "Eastern Asia",
-    ),
-    (
-        "+36",
-        "\u{1F1ED}\u{1F1FA}",
-        "+36 .. .......",
-        "Hungary",
-        "Europe",
-        "Eastern Europe",
-    ),
-    (
-        "+354",
-        "\u{1F1EE}\u{1F1F8}",
-        "+354 ... ....",
-        "Iceland",
-        "Europe",
-        "Northern Europe",
-    ),
-    (
-        "+91",
-        "\u{1F1EE}\u{1F1F3}",
-        "+91 .. ... ....",
-        "India",
-        "Asia",
-        "Southern Asia",
-    ),
-    (
-        "+62",
-        "\u{1F1EE}\u{1F1E9}",
-        "+62 .. .......",
-        "Indonesia",
-        "Asia",
-        "South-Eastern Asia",
-    ),
-    (
-        "+98",
-        "\u{1F1EE}\u{1F1F7}",
-        "+98 .. .... ...",
-        "Iran",
-        "Asia",
-        "Southern Asia",
-    ),
-    (
-        "+964",
-        "\u{1F1EE}\u{1F1F6}",
-        "+964 ... ....",
-        "Iraq",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+353",
-        "\u{1F1EE}\u{1F1EA}",
-        "+353 ... ....",
-        "Ireland",
-        "Europe",
-        "Northern Europe",
-    ),
-    (
-        "+44",
-        "\u{1F1EE}\u{1F1F2}",
-        "+44 .. .... ..",
-        "Isle of Man",
-        "Europe",
-        "Northern Europe",
-    ),
-    (
-        "+972",
-        "\u{1F1EE}\u{1F1F1}",
-        "+972 ... ....",
-        "Israel",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+39",
-        "\u{1F1EE}\u{1F1F9}",
-        "+39 .. ... ....",
-        "Italy",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+1876",
-        "\u{1F1EF}\u{1F1F2}",
-        "+1876 ... ....",
-        "Jamaica",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+81",
-        "\u{1F1EF}\u{1F1F5}",
-        "+81 .. .... ....",
-        "Japan",
-        "Asia",
-        "Eastern Asia",
-    ),
-    (
-        "+44",
-        "\u{1F1EF}\u{1F1EA}",
-        "+44 .. .... ..",
-        "Jersey",
-        "Europe",
-        "Northern Europe",
-    ),
-    (
-        "+962",
-        "\u{1F1EF}\u{1F1F4}",
-        "+962 ... ....",
-        "Jordan",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+7",
-        "\u{1F1F0}\u{1F1FF}",
-        "+7 .. ... ......",
-        "Kazakhstan",
-        "Asia",
-        "Central Asia",
-    ),
-    (
-        "+254",
-        "\u{1F1F0}\u{1F1EA}",
-        "+254 ... ....",
-        "Kenya",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+686",
-        "\u{1F1F0}\u{1F1EE}",
-        "+686 ... ....",
-        "Kiribati",
-        "Oceania",
-        "Micronesia",
-    ),
-    (
-        "+850",
-        "\u{1F1F0}\u{1F1F5}",
-        "+850 ... ....",
-        "North Korea",
-        "Asia",
-        "Eastern Asia",
-    ),
-    (
-        "+82",
-        "\u{1F1F0}\u{1F1F7}",
-        "+82 .. ... ....",
-        "South Korea",
-        "Asia",
-        "Eastern Asia",
-    ),
-    (
-        "+383",
-        "\u{1F1FD}\u{1F1F0}",
-        "+383 ... ....",
-        "Kosovo",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+965",
-        "\u{1F1F0}\u{1F1FC}",
-        "+965 ... ....",
-        "Kuwait",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+996",
-        "\u{1F1F0}\u{1F1EC}",
-        "+996 ... ....",
-        "Kyrgyzstan",
-        "Asia",
-        "Central Asia",
-    ),
-    (
-        "+856",
-        "\u{1F1F1}\u{1F1E6}",
-        "+856 ... ....",
-        "Laos",
-        "Asia",
-        "South-Eastern Asia",
-    ),
-    (
-        "+371",
-        "\u{1F1F1}\u{1F1FB}",
-        "+371 ... ....",
-        "Latvia",
-        "Europe",
-        "Northern Europe",
-    ),
-    (
-        "+961",
-        "\u{1F1F1}\u{1F1E7}",
-        "+961 ... ....",
-        "Lebanon",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+266",
-        "\u{1F1F1}\u{1F1F8}",
-        "+266 ... ....",
-        "Lesotho",
-        "Africa",
-        "Southern Africa",
-    ),
-    (
-        "+231",
-        "\u{1F1F1}\u{1F1F7}",
-        "+231 ... ....",
-        "Liberia",
-        "Africa",
-        "Western Africa",
-    ),
-    (
-        "+218",
-        "\u{1F1F1}\u{1F1FE}",
-        "+218 ... ....",
-        "Libya",
-        "Africa",
-        "Northern Africa",
-    ),
-    (
-        "+423",
-        "\u{1F1F1}\u{1F1EE}",
-        "+423 ... ....",
-        "Liechtenstein",
-        "Europe",
-        "Western Europe",
-    ),
-    (
-        "+370",
-        "\u{1F1F1}\u{1F1F9}",
-        "+370 ... ....",
-        "Lithuania",
-        "Europe",
-        "Northern Europe",
-    ),
-    (
-        "+352",
-        "\u{1F1F1}\u{1F1FA}",
-        "+352 ... ....",
-        "Luxembourg",
-        "Europe",
-        "Western Europe",
-    ),
-    (
-        "+853",
-        "\u{1F1F2}\u{1F1F4}",
-        "+853 ... ....",
-        "Macau",
-        "Asia",
-        "Eastern Asia",
-    ),
-    (
-        "+389",
-        "\u{1F1F2}\u{1F1F0}",
-        "+389 ... ....",
-        "North Macedonia",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+261",
-        "\u{1F1F2}\u{1F1EC}",
-        "+261 ... ....",
-        "Madagascar",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+265",
-        "\u{1F1F2}\u{1F1FC}",
-        "+265 ... ....",
-        "Malawi",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+60",
-        "\u{1F1F2}\u{1F1FE}",
-        "+60 .. ... ...",
-        "Malaysia",
-        "Asia",
-        "South-Eastern Asia",
-    ),
-    (
-        "+960",
-        "\u{1F1F2}\u{1F1FB}",
-        "+960 ... ....",
-        "Maldives",
-        "Asia",
-        "Southern Asia",
-    ),
-    (
-        "+223",
-        "\u{1F1F2}\u{1F1F1}",
-        "+223 ... ....",
-        "Mali",
-        "Africa",
-        "Western Africa",
-    ),
-    (
-        "+356",
-        "\u{1F1F2}\u{1F1F9}",
-        "+356 ... ....",
-        "Malta",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+692",
-        "\u{1F1F2}\u{1F1ED}",
-        "+692 ... ....",
-        "Marshall Islands",
-        "Oceania",
-        "Micronesia",
-    ),
-    (
-        "+596",
-        "\u{1F1F2}\u{1F1F6}",
-        "+596 ... ....",
-        "Martinique",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+222",
-        "\u{1F1F2}\u{1F1F7}",
-        "+222 ... ....",
-        "Mauritania",
-        "Africa",
-        "Western Africa",
-    ),
-    (
-        "+230",
-        "\u{1F1F2}\u{1F1FA}",
-        "+230 ... ....",
-        "Mauritius",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+262",
-        "\u{1F1FE}\u{1F1F9}",
-        "+262 ... ....",
-        "Mayotte",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+52",
-        "\u{1F1F2}\u{1F1FD}",
-        "+52 .. .... ....",
-        "Mexico",
-        "America",
-        "Central America",
-    ),
-    (
-        "+691",
-        "\u{1F1EB}\u{1F1F2}",
-        "+691 ... ....",
-        "Micronesia",
-        "Oceania",
-        "Micronesia",
-    ),
-    (
-        "+373",
-        "\u{1F1F2}\u{1F1E9}",
-        "+373 ... ....",
-        "Moldova",
-        "Europe",
-        "Eastern Europe",
-    ),
-    (
-        "+377",
-        "\u{1F1F2}\u{1F1E8}",
-        "+377 ... ....",
-        "Monaco",
-        "Europe",
-        "Western Europe",
-    ),
-    (
-        "+976",
-        "\u{1F1F2}\u{1F1F3}",
-        "+976 ... ....",
-        "Mongolia",
-        "Asia",
-        "Eastern Asia",
-    ),
-    (
-        "+382",
-        "\u{1F1F2}\u{1F1EA}",
-        "+382 ... ....",
-        "Montenegro",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+1664",
-        "\u{1F1F2}\u{1F1F8}",
-        "+1664 ... ....",
-        "Montserrat",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+212",
-        "\u{1F1EA}\u{1F1ED}",
-        "+212 ... ....",
-        "Morocco",
-        "Africa",
-        "Northern Africa",
-    ),
-    (
-        "+258",
-        "\u{1F1F2}\u{1F1FF}",
-        "+258 ... ....",
-        "Mozambique",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+95",
-        "\u{1F1F2}\u{1F1F2}",
-        "+95 .. .... ....",
-        "Myanmar",
-        "Asia",
-        "South-Eastern Asia",
-    ),
-    (
-        "+264",
-        "\u{1F1F3}\u{1F1E6}",
-        "+264 ... ....",
-        "Namibia",
-        "Africa",
-        "Southern Africa",
-    ),
-    (
-        "+674",
-        "\u{1F1F3}\u{1F1F7}",
-        "+674 ... ....",
-        "Nauru",
-        "Oceania",
-        "Micronesia",
-    ),
-    (
-        "+977",
-        "\u{1F1F3}\u{1F1F5}",
-        "+977 ... ....",
-        "Nepal",
-        "Asia",
-        "Southern Asia",
-    ),
-    (
-        "+31",
-        "\u{1F1F3}\u{1F1F1}",
-        "+31 .. ... ..",
-        "Netherlands",
-        "Europe",
-        "Western Europe",
-    ),
-    (
-        "+599",
-        "\u{1F1E7}\u{1F1F6}",
-        "+599 ... ....",
-        "Netherlands Antilles",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+687",
-        "\u{1F1F3}\u{1F1E8}",
-        "+687 ... ....",
-        "New Caledonia",
-        "Oceania",
-        "Melanesia",
-    ),
-    (
-        "+64",
-        "\u{1F1F3}\u{1F1FF}",
-        "+64 .. ... ....",
-        "New Zealand",
-        "Oceania",
-        "Australia",
-    ),
-    (
-        "+505",
-        "\u{1F1F3}\u{1F1EE}",
-        "+505 ... ....",
-        "Nicaragua",
-        "America",
-        "Central America",
-    ),
-    (
-        "+227",
-        "\u{1F1F3}\u{1F1EA}",
-        "+227 ... ....",
-        "Niger",
-        "Africa",
-        "Western Africa",
-    ),
-    (
-        "+234",
-        "\u{1F1F3}\u{1F1EC}",
-        "+234 ... ....",
-        "Nigeria",
-        "Africa",
-        "Western Africa",
-    ),
-    (
-        "+683",
-        "\u{1F1F3}\u{1F1FA}",
-        "+683 ... ....",
-        "Niue",
-        "Oceania",
-        "Polynesia",
-    ),
-    (
-        "+672",
-        "\u{1F1F3}\u{1F1EB}",
-        "+672 ... ....",
-        "Norfolk Island",
-        "Oceania",
-        "Australia",
-    ),
-    (
-        "+1670",
-        "\u{1F1F2}\u{1F1F5}",
-        "+1670 ... ....",
-        "Northern Mariana Islands",
-        "Oceania",
-        "Micronesia",
-    ),
-    (
-        "+47",
-        "\u{1F1F3}\u{1F1F4}",
-        "+47 .. ... ....",
-        "Norway",
-        "Europe",
-        "Northern Europe",
-    ),
-    (
-        "+968",
-        "\u{1F1F4}\u{1F1F2}",
-        "+968 ... ....",
-        "Oman",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+92",
-        "\u{1F1F5}\u{1F1F0}",
-        "+92 .. ... ..",
-        "Pakistan",
-        "Asia",
-        "Southern Asia",
-    ),
-    (
-        "+680",
-        "\u{1F1F5}\u{1F1FC}",
-        "+680 ... ....",
-        "Palau",
-        "Oceania",
-        "Micronesia",
-    ),
-    (
-        "+970",
-        "\u{1F1F5}\u{1F1F8}",
-        "+970 ... ....",
-        "Palestinian Territories",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+507",
-        "\u{1F1F5}\u{1F1E6}",
-        "+507 ... ....",
-        "Panama",
-        "America",
-        "Central America",
-    ),
-    (
-        "+675",
-        "\u{1F1F5}\u{1F1EC}",
-        "+675 ... ....",
-        "Papua New Guinea",
-        "Oceania",
-        "Melanesia",
-    ),
-    (
-        "+595",
-        "\u{1F1F5}\u{1F1FE}",
-        "+595 ... ....",
-        "Paraguay",
-        "South America",
-        "South America",
-    ),
-    (
-        "+51",
-        "\u{1F1F5}\u{1F1EA}",
-        "+51 .. .... ....",
-        "Peru",
-        "America",
-        "South America",
-    ),
-    (
-        "+63",
-        "\u{1F1F5}\u{1F1ED}",
-        "+63 .. ... ....",
-        "Philippines",
-        "Asia",
-        "South-Eastern Asia",
-    ),
-    (
-        "+48",
-        "\u{1F1F5}\u{1F1F1}",
-        "+48 .. .... ....",
-        "Poland",
-        "Europe",
-        "Eastern Europe",
-    ),
-    (
-        "+351",
-        "\u{1F1F5}\u{1F1F9}",
-        "+351 ... ....",
-        "Portugal",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+1",
-        "\u{1F1F5}\u{1F1F7}",
-        "+1 ... ... ....",
-        "Puerto Rico",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+974",
-        "\u{1F1F6}\u{1F1E6}",
-        "+974 ... ....",
-        "Qatar",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+262",
-        "\u{1F1F7}\u{1F1EA}",
-        "+262 ... ....",
-        "R\u{E9}union",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+40",
-        "\u{1F1F7}\u{1F1F4}",
-        "+40 .. .... ....",
-        "Romania",
-        "Europe",
-        "Eastern Europe",
-    ),
-    (
-        "+7",
-        "\u{1F1F7}\u{1F1FA}",
-        "+7 .. ... ......",
-        "Russia",
-        "Europe",
-        "Eastern Europe",
-    ),
-    (
-        "+250",
-        "\u{1F1F7}\u{1F1FC}",
-        "+250 ... ....",
-        "Rwanda",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+290",
-        "\u{1F1F8}\u{1F1ED}",
-        "+290 ... ....",
-        "Saint Helena",
-        "Africa",
-        "Atlantic",
-    ),
-    (
-        "+1869",
-        "\u{1F1F0}\u{1F1F3}",
-        "+1869 ... ....",
-        "Saint Kitts and Nevis",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+1758",
-        "\u{1F1F1}\u{1F1E8}",
-        "+1758 ... ....",
-        "Saint Lucia",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+590",
-        "\u{1F1EC}\u{1F1F5}",
-        "+590 ... ....",
-        "Saint Pierre and Miquelon",
-        "America",
-        "Northern America",
-    ),
-    (
-        "+1784",
-        "\u{1F1FB}\u{1F1E8}",
-        "+1784 ... ....",
-        "Saint Vincent and the Grenadines",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+685",
-        "\u{1F1FC}\u{1F1F8}",
-        "+685 ... ....",
-        "Samoa",
-        "Oceania",
-        "Polynesia",
-    ),
-    (
-        "+378",
-        "\u{1F1F8}\u{1F1F2}",
-        "+378 ... ....",
-        "San Marino",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+239",
-        "\u{1F1F8}\u{1F1F9}",
-        "+239 ... ....",
-        "S\u{E3}o Tom\u{E9} and Pr\u{ED}ncipe",
-        "Africa",
-        "Middle Africa",
-    ),
-    (
-        "+966",
-        "\u{1F1F8}\u{1F1E6}",
-        "+966 ... ....",
-        "Saudi Arabia",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+221",
-        "\u{1F1F8}\u{1F1F3}",
-        "+221 ... ....",
-        "Senegal",
-        "Africa",
-        "Western Africa",
-    ),
-    (
-        "+381",
-        "\u{1F1F7}\u{1F1F8}",
-        "+381 ... ....",
-        "Serbia",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+248",
-        "\u{1F1F8}\u{1F1E8}",
-        "+248 ... ....",
-        "Seychelles",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+232",
-        "\u{1F1F8}\u{1F1F1}",
-        "+232 ... ....",
-        "Sierra Leone",
-        "Africa",
-        "Western Africa",
-    ),
-    (
-        "+65",
-        "\u{1F1F8}\u{1F1EC}",
-        "+65 ... ....",
-        "Singapore",
-        "Asia",
-        "South-Eastern Asia",
-    ),
-    (
-        "+1721",
-        "\u{1F1F8}\u{1F1FD}",
-        "+1721 ... ....",
-        "Sint Maarten",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+421",
-        "\u{1F1F8}\u{1F1F0}",
-        "+421 ... ....",
-        "Slovakia",
-        "Europe",
-        "Eastern Europe",
-    ),
-    (
-        "+386",
-        "\u{1F1F8}\u{1F1EE}",
-        "+386 ... ....",
-        "Slovenia",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+677",
-        "\u{1F1F8}\u{1F1E7}",
-        "+677 ... ....",
-        "Solomon Islands",
-        "Oceania",
-        "Melanesia",
-    ),
-    (
-        "+252",
-        "\u{1F1F8}\u{1F1F4}",
-        "+252 ... ....",
-        "Somalia",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+27",
-        "\u{1F1FF}\u{1F1E6}",
-        "+27 .. .... ....",
-        "South Africa",
-        "Africa",
-        "Southern Africa",
-    ),
-    (
-        "+211",
-        "\u{1F1F8}\u{1F1F8}",
-        "+211 ... ....",
-        "South Sudan",
-        "Africa",
-        "Middle Africa",
-    ),
-    (
-        "+34",
-        "\u{1F1EA}\u{1F1F8}",
-        "+34 .. ... ....",
-        "Spain",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+94",
-        "\u{1F1F1}\u{1F1F0}",
-        "+94 .. ... ....",
-        "Sri Lanka",
-        "Asia",
-        "Southern Asia",
-    ),
-    (
-        "+249",
-        "\u{1F1F8}\u{1F1E9}",
-        "+249 ... ....",
-        "Sudan",
-        "Africa",
-        "Northern Africa",
-    ),
-    (
-        "+597",
-        "\u{1F1F8}\u{1F1F7}",
-        "+597 ... ....",
-        "Suriname",
-        "America",
-        "South America",
-    ),
-    (
-        "+47",
-        "\u{1F1F8}\u{1F1EF}",
-        "+47 .. ... ....",
-        "Svalbard and Jan Mayen",
-        "Europe",
-        "Northern Europe",
-    ),
-    (
-        "+268",
-        "\u{1F1F8}\u{1F1FF}",
-        "+268 ... ....",
-        "Swaziland",
-        "Africa",
-        "Southern Africa",
-    ),
-    (
-        "+46",
-        "\u{1F1F8}\u{1F1EA}",
-        "+46 .. ... ....",
-        "Sweden",
-        "Europe",
-        "Northern Europe",
-    ),
-    (
-        "+41",
-        "\u{1F1E8}\u{1F1ED}",
-        "+41 .. ... ....",
-        "Switzerland",
-        "Europe",
-        "Western Europe",
-    ),
-    (
-        "+963",
-        "\u{1F1F8}\u{1F1FE}",
-        "+963 ... ....",
-        "Syria",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+886",
-        "\u{1F1F9}\u{1F1FC}",
-        "+886 ... ....",
-        "Taiwan",
-        "Asia",
-        "Eastern Asia",
-    ),
-    (
-        "+992",
-        "\u{1F1F9}\u{1F1EF}",
-        "+992 ... ....",
-        "Tajikistan",
-        "Asia",
-        "Central Asia",
-    ),
-    (
-        "+255",
-        "\u{1F1F9}\u{1F1FF}",
-        "+255 ... ....",
-        "Tanzania",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+66",
-        "\u{1F1F9}\u{1F1ED}",
-        "+66 .. ... ....",
-        "Thailand",
-        "Asia",
-        "South-Eastern Asia",
-    ),
-    (
-        "+670",
-        "\u{1F1F9}\u{1F1F1}",
-        "+670 ... ....",
-        "Timor-Leste",
-        "Asia",
-        "South-Eastern Asia",
-    ),
-    (
-        "+228",
-        "\u{1F1F9}\u{1F1EC}",
-        "+228 ... ....",
-        "Togo",
-        "Africa",
-        "Western Africa",
-    ),
-    (
-        "+690",
-        "\u{1F1F9}\u{1F1F0}",
-        "+690 ... ....",
-        "Tokelau",
-        "Oceania",
-        "Polynesia",
-    ),
-    (
-        "+676",
-        "\u{1F1F9}\u{1F1F4}",
-        "+676 ... ....",
-        "Tonga",
-        "Oceania",
-        "Polynesia",
-    ),
-    (
-        "+1868",
-        "\u{1F1F9}\u{1F1F9}",
-        "+1868 ... ....",
-        "Trinidad and Tobago",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+216",
-        "\u{1F1F9}\u{1F1F3}",
-        "+216 ... ....",
-        "Tunisia",
-        "Africa",
-        "Northern Africa",
-    ),
-    (
-        "+90",
-        "\u{1F1F9}\u{1F1F7}",
-        "+90 .. ... ....",
-        "Turkey",
-        "Europe",
-        "Western Asia",
-    ),
-    (
-        "+993",
-        "\u{1F1F9}\u{1F1F2}",
-        "+993 ... ....",
-        "Turkmenistan",
-        "Asia",
-        "Central Asia",
-    ),
-    (
-        "+1649",
-        "\u{1F1F9}\u{1F1E8}",
-        "+1649 ... ....",
-        "Turks and Caicos Islands",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+688",
-        "\u{1F1F9}\u{1F1FB}",
-        "+688 ... ....",
-        "Tuvalu",
-        "Oceania",
-        "Polynesia",
-    ),
-    (
-        "+256",
-        "\u{1F1FA}\u{1F1EC}",
-        "+256 ... ....",
-        "Uganda",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+380",
-        "\u{1F1FA}\u{1F1E6}",
-        "+380 ... ....",
-        "Ukraine",
-        "Europe",
-        "Eastern Europe",
-    ),
-    (
-        "+971",
-        "\u{1F1E6}\u{1F1EA}",
-        "+971 ... ....",
-        "United Arab Emirates",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+44",
-        "\u{1F1EC}\u{1F1E7}",
-        "+44 .. .... ..",
-        "United Kingdom",
-        "Europe",
-        "Northern Europe",
-    ),
-    (
-        "+1",
-        "\u{1F1FA}\u{1F1F8}",
-        "+1 ... ... ....",
-        "United States",
-        "America",
-        "Northern America",
-    ),
-    (
-        "+598",
-        "\u{1F1FA}\u{1F1FE}",
-        "+598 ... ....",
-        "Uruguay",
-        "America",
-        "South America",
-    ),
-    (
-        "+998",
-        "\u{1F1FA}\u{1F1FF}",
-        "+998 ... ....",
-        "Uzbekistan",
-        "Asia",
-        "Central Asia",
-    ),
-    (
-        "+678",
-        "\u{1F1FB}\u{1F1FA}",
-        "+678 ... ....",
-        "Vanuatu",
-        "Oceania",
-        "Melanesia",
-    ),
-    (
-        "+39",
-        "\u{1F1FB}\u{1F1E6}",
-        "+39 .. ... ....",
-        "Vatican City",
-        "Europe",
-        "Southern Europe",
-    ),
-    (
-        "+58",
-        "\u{1F1FB}\u{1F1EA}",
-        "+58 .. .... ....",
-        "Venezuela",
-        "America",
-        "South America",
-    ),
-    (
-        "+84",
-        "\u{1F1FB}\u{1F1F3}",
-        "+84 .. .... ....",
-        "Vietnam",
-        "Asia",
-        "South-Eastern Asia",
-    ),
-    (
-        "+1284",
-        "\u{1F1FB}\u{1F1EC}",
-        "+1284 ... ....",
-        "Virgin Islands (UK)",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+1340",
-        "\u{1F1FB}\u{1F1EE}",
-        "+1340 ... ....",
-        "Virgin Islands (US)",
-        "America",
-        "Caribbean",
-    ),
-    (
-        "+681",
-        "\u{1F1FC}\u{1F1EB}",
-        "+681 ... ....",
-        "Wallis and Futuna",
-        "Oceania",
-        "Polynesia",
-    ),
-    (
-        "+212",
-        "\u{1F1EA}\u{1F1ED}",
-        "+212 ... ....",
-        "Western Sahara",
-        "Africa",
-        "Northern Africa",
-    ),
-    (
-        "+967",
-        "\u{1F1FE}\u{1F1EA}",
-        "+967 ... ....",
-        "Yemen",
-        "Asia",
-        "Western Asia",
-    ),
-    (
-        "+260",
-        "\u{1F1FF}\u{1F1F2}",
-        "+260 ... ....",
-        "Zambia",
-        "Africa",
-        "Eastern Africa",
-    ),
-    (
-        "+263",
-        "\u{1F1FF}\u{1F1FC}",
-        "+263 ... ....",
-        "Zimbabwe",
-        "Africa",
-        "Eastern Africa",
-    ),
+/// A country with its international dialing code and display metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Country {
+    /// The international dialing code, e.g. "+44".
+    pub code: &'static str,
+    /// The flag emoji of the country.
+    pub flag: &'static str,
+    /// The phone number format, where each `.` stands for a digit, e.g. "+33 .. .. .. ..".
+    pub format: &'static str,
+    /// The name of the country.
+    pub name: &'static str,
+    /// The continent the country belongs to.
+    pub continent: &'static str,
+    /// The region of the continent the country belongs to.
+    pub region: &'static str,
+}
+
+/// The order of the countries offered by the tel input.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum CountrySort {
+    /// The order of the country list.
+    #[default]
+    Listed,
+    /// Alphabetical order of the country names.
+    Alphabetical,
+    /// Ascending numeric order of the dialing codes, e.g. `+1` before `+7` before `+20`. North American
+    /// codes such as `+1264` follow `+1`, ordered by their area code.
+    ByDialCode,
+    /// The countries with the given names first, in that order, followed by the others.
+    Custom(Vec<&'static str>),
+}
+
+/// sort_countries
+/// Sorts countries in place following `sort`. The sort is stable, so countries sharing a dialing code, or
+/// left out of a custom order, keep their current order.
+///
+/// # Arguments
+/// * `countries` - The countries to sort.
+/// * `sort` - The order to sort them in.
+///
+/// # Examples
+/// ```
+/// use input_yew::countries::{sort_countries, Country, CountrySort};
+///
+/// let country = |code, name| Country { code, flag: "", format: "", name, continent: "", region: "" };
+/// let listed = [
+///     country("+20", "Egypt"),
+///     country("+1264", "Anguilla"),
+///     country("+7", "Russia"),
+///     country("+33", "France"),
+///     country("+1", "United States"),
+///     country("+49", "Germany"),
+/// ];
+/// let mut countries: Vec<&Country> = listed.iter().collect();
+///
+/// sort_countries(&mut countries, &CountrySort::ByDialCode);
+/// let codes: Vec<&str> = countries.iter().map(|country| country.code).collect();
+/// assert_eq!(codes, ["+1", "+1264", "+7", "+20", "+33", "+49"]);
+///
+/// sort_countries(&mut countries, &CountrySort::Custom(vec!["Germany", "France"]));
+/// assert_eq!(countries[0].name, "Germany");
+/// assert_eq!(countries[1].name, "France");
+/// ```
+pub fn sort_countries(countries: &mut [&Country], sort: &CountrySort) {
+    match sort {
+        CountrySort::Listed => {}
+        CountrySort::Alphabetical => countries.sort_by_key(|country| country.name.to_lowercase()),
+        CountrySort::ByDialCode => countries.sort_by_key(|country| dial_code_key(country.code)),
+        CountrySort::Custom(names) => countries.sort_by_key(|country| {
+            names
+                .iter()
+                .position(|name| *name == country.name)
+                .unwrap_or(names.len())
+        }),
+    }
+}
+
+/// Returns the numeric sort key of a dialing code: the country calling code, then the area code of North
+/// American codes, which share the `+1` calling code.
+fn dial_code_key(code: &str) -> (u32, u32) {
+    let digits = code.trim_start_matches('+');
+    match digits.strip_prefix('1') {
+        Some(area) => (1, area.parse().unwrap_or(0)),
+        None => (digits.parse().unwrap_or(u32::MAX), 0),
+    }
+}
+
+/// search_countries
+/// Finds the countries whose name contains `query`, ignoring case, or whose dialing code starts with it.
+///
+/// # Arguments
+/// * `query` - A part of a country name, or a dialing code with or without its leading `+`.
+///
+/// # Returns
+/// (Vec<&Country>): The matching countries in `COUNTRY_CODES` order, or every country for a blank query.
+///
+/// # Examples
+/// ```
+/// use input_yew::countries::search_countries;
+///
+/// assert_eq!(search_countries("fran")[0].name, "France");
+/// assert!(search_countries("+33").iter().any(|country| country.name == "France"));
+/// assert!(search_countries("33").iter().all(|country| country.code.starts_with("+33")));
+/// ```
+#[cfg(feature = "phone")]
+pub fn search_countries(query: &str) -> Vec<&'static Country> {
+    search_countries_in(&COUNTRY_CODES, query)
+}
+
+/// search_countries_in
+/// Finds the countries of a custom list whose name contains `query`, ignoring case, or whose dialing code
+/// starts with it, see `search_countries`.
+///
+/// # Arguments
+/// * `countries` - The countries to search.
+/// * `query` - A part of a country name, or a dialing code with or without its leading `+`.
+///
+/// # Returns
+/// (Vec<&Country>): The matching countries in list order, or every country for a blank query.
+#[cfg(feature = "phone")]
+pub fn search_countries_in(countries: &'static [Country], query: &str) -> Vec<&'static Country> {
+    let query = query.trim().to_lowercase();
+    let code = format!("+{}", query.trim_start_matches('+'));
+    countries
+        .iter()
+        .filter(|country| {
+            query.is_empty()
+                || country.name.to_lowercase().contains(&query)
+                || country.code.starts_with(&code)
+        })
+        .collect()
+}
+
+impl Country {
+    /// Returns the fields of the country as a `(code, flag, format, name, continent, region)` tuple, the
+    /// shape of the entries of `COUNTRY_CODES` before 0.2.
+    pub fn as_tuple(&self) -> CountryTuple {
+        (self.code, self.flag, self.format, self.name, self.continent, self.region)
+    }
+}
+
+/// A country as a `(code, flag, format, name, continent, region)` tuple, see `Country::as_tuple`.
+pub type CountryTuple = (
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+);
+
+/// country_code_tuples
+/// Lists every country as a tuple, for code written against the tuple entries of `COUNTRY_CODES` before
+/// 0.2.
+///
+/// # Returns
+/// (impl Iterator<Item = CountryTuple>): The countries in `COUNTRY_CODES` order.
+///
+/// # Examples
+/// ```
+/// #![allow(deprecated)]
+/// use input_yew::countries::country_code_tuples;
+///
+/// let (code, _, _, name, _, _) = country_code_tuples().next().unwrap();
+/// assert_eq!((code, name), ("+93", "Afghanistan"));
+/// ```
+#[cfg(feature = "phone")]
+#[deprecated(since = "0.2.0", note = "use the `Country` fields of `COUNTRY_CODES` instead")]
+pub fn country_code_tuples() -> impl Iterator<Item = CountryTuple> {
+    COUNTRY_CODES.iter().map(Country::as_tuple)
+}
+
+/// Every country with its dialing code. Only available with the `phone` feature, as it noticeably grows
+/// the wasm bundle.
+#[cfg(feature = "phone")]
+pub static COUNTRY_CODES: [Country; 246] = [
+    Country {
+        code: "+93",
+        flag: "\u{1F1E6}\u{1F1EB}",
+        format: "+93 ...-....",
+        name: "Afghanistan",
+        continent: "Asia",
+        region: "Southern Asia",
+    },
+    Country {
+        code: "+355",
+        flag: "\u{1F1E6}\u{1F1F1}",
+        format: "+355 ... ....",
+        name: "Albania",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+213",
+        flag: "\u{1F1E9}\u{1F1FF}",
+        format: "+213 ... .. ..",
+        name: "Algeria",
+        continent: "Africa",
+        region: "Northern Africa",
+    },
+    Country {
+        code: "+376",
+        flag: "\u{1F1E6}\u{1F1E9}",
+        format: "+376 ... ...",
+        name: "Andorra",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+244",
+        flag: "\u{1F1E6}\u{1F1F4}",
+        format: "+244 ... ....",
+        name: "Angola",
+        continent: "Africa",
+        region: "Middle Africa",
+    },
+    Country {
+        code: "+1264",
+        flag: "\u{1F1E6}\u{1F1EE}",
+        format: "+1264 ... ....",
+        name: "Anguilla",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+1268",
+        flag: "\u{1F1E6}\u{1F1EC}",
+        format: "+1268 ... ....",
+        name: "Antigua and Barbuda",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+54",
+        flag: "\u{1F1E6}\u{1F1F7}",
+        format: "+54 ... .......",
+        name: "Argentina",
+        continent: "America",
+        region: "South America",
+    },
+    Country {
+        code: "+374",
+        flag: "\u{1F1E6}\u{1F1F2}",
+        format: "+374 ... ....",
+        name: "Armenia",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+297",
+        flag: "\u{1F1E6}\u{1F1FC}",
+        format: "+297 ... ....",
+        name: "Aruba",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+247",
+        flag: "\u{1F1E6}\u{1F1F8}",
+        format: "+247 ...-....",
+        name: "Ascension Island",
+        continent: "Africa",
+        region: "Atlantic",
+    },
+    Country {
+        code: "+61",
+        flag: "\u{1F1E6}\u{1F1FA}",
+        format: "+61 .. ... ...",
+        name: "Australia",
+        continent: "Oceania",
+        region: "Australia",
+    },
+    Country {
+        code: "+672",
+        flag: "\u{1F1E6}\u{1F1FA}",
+        format: "+672 .. ... ...",
+        name: "Australian External Territories",
+        continent: "Oceania",
+        region: "Australia",
+    },
+    Country {
+        code: "+43",
+        flag: "\u{1F1E6}\u{1F1F9}",
+        format: "+43 ... .......",
+        name: "Austria",
+        continent: "Europe",
+        region: "Western Europe",
+    },
+    Country {
+        code: "+994",
+        flag: "\u{1F1E6}\u{1F1FF}",
+        format: "+994 ... .. ..",
+        name: "Azerbaijan",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+1242",
+        flag: "\u{1F1E7}\u{1F1F8}",
+        format: "+1242 ... ....",
+        name: "Bahamas",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+973",
+        flag: "\u{1F1E7}\u{1F1ED}",
+        format: "+973 ... ....",
+        name: "Bahrain",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+880",
+        flag: "\u{1F1E7}\u{1F1E9}",
+        format: "+880 ...-.....",
+        name: "Bangladesh",
+        continent: "Asia",
+        region: "Southern Asia",
+    },
+    Country {
+        code: "+1246",
+        flag: "\u{1F1E7}\u{1F1E7}",
+        format: "+1246 ... ....",
+        name: "Barbados",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+375",
+        flag: "\u{1F1E7}\u{1F1FE}",
+        format: "+375 ... ....",
+        name: "Belarus",
+        continent: "Europe",
+        region: "Eastern Europe",
+    },
+    Country {
+        code: "+32",
+        flag: "\u{1F1E7}\u{1F1EA}",
+        format: "+32 .. ... ..",
+        name: "Belgium",
+        continent: "Europe",
+        region: "Western Europe",
+    },
+    Country {
+        code: "+501",
+        flag: "\u{1F1E7}\u{1F1FF}",
+        format: "+501 ...-....",
+        name: "Belize",
+        continent: "America",
+        region: "Central America",
+    },
+    Country {
+        code: "+229",
+        flag: "\u{1F1E7}\u{1F1EF}",
+        format: "+229 ... ....",
+        name: "Benin",
+        continent: "Africa",
+        region: "Western Africa",
+    },
+    Country {
+        code: "+1441",
+        flag: "\u{1F1E7}\u{1F1F2}",
+        format: "+1441 ... ....",
+        name: "Bermuda",
+        continent: "America",
+        region: "Northern America",
+    },
+    Country {
+        code: "+975",
+        flag: "\u{1F1E7}\u{1F1F9}",
+        format: "+975 ... ....",
+        name: "Bhutan",
+        continent: "Asia",
+        region: "Southern Asia",
+    },
+    Country {
+        code: "+591",
+        flag: "\u{1F1E7}\u{1F1F4}",
+        format: "+591 ... ....",
+        name: "Bolivia",
+        continent: "America",
+        region: "South America",
+    },
+    Country {
+        code: "+599",
+        flag: "\u{1F1E7}\u{1F1F6}",
+        format: "+599 ... ....",
+        name: "Bonaire",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+387",
+        flag: "\u{1F1E7}\u{1F1E6}",
+        format: "+387 ... ....",
+        name: "Bosnia and Herzegovina",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+267",
+        flag: "\u{1F1E7}\u{1F1FC}",
+        format: "+267 ... ....",
+        name: "Botswana",
+        continent: "Africa",
+        region: "Southern Africa",
+    },
+    Country {
+        code: "+55",
+        flag: "\u{1F1E7}\u{1F1F7}",
+        format: "+55 .. .......",
+        name: "Brazil",
+        continent: "America",
+        region: "South America",
+    },
+    Country {
+        code: "+246",
+        flag: "\u{1F1EE}\u{1F1F4}",
+        format: "+246 ...-....",
+        name: "British Indian Ocean Territory",
+        continent: "Africa",
+        region: "Indian Ocean",
+    },
+    Country {
+        code: "+1284",
+        flag: "\u{1F1FB}\u{1F1EC}",
+        format: "+1284 ... ....",
+        name: "British Virgin Islands",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+673",
+        flag: "\u{1F1E7}\u{1F1F3}",
+        format: "+673 ... ....",
+        name: "Brunei",
+        continent: "Asia",
+        region: "South-Eastern Asia",
+    },
+    Country {
+        code: "+359",
+        flag: "\u{1F1E7}\u{1F1EC}",
+        format: "+359 ... ....",
+        name: "Bulgaria",
+        continent: "Europe",
+        region: "Eastern Europe",
+    },
+    Country {
+        code: "+226",
+        flag: "\u{1F1E7}\u{1F1EB}",
+        format: "+226 ... ....",
+        name: "Burkina Faso",
+        continent: "Africa",
+        region: "Western Africa",
+    },
+    Country {
+        code: "+257",
+        flag: "\u{1F1E7}\u{1F1EE}",
+        format: "+257 ... ....",
+        name: "Burundi",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+855",
+        flag: "\u{1F1F0}\u{1F1ED}",
+        format: "+855 ...-....",
+        name: "Cambodia",
+        continent: "Asia",
+        region: "South-Eastern Asia",
+    },
+    Country {
+        code: "+237",
+        flag: "\u{1F1E8}\u{1F1F2}",
+        format: "+237 ... .. ..",
+        name: "Cameroon",
+        continent: "Africa",
+        region: "Middle Africa",
+    },
+    Country {
+        code: "+1",
+        flag: "\u{1F1E8}\u{1F1E6}",
+        format: "+1 ... ... ....",
+        name: "Canada",
+        continent: "America",
+        region: "Northern America",
+    },
+    Country {
+        code: "+238",
+        flag: "\u{1F1E8}\u{1F1FB}",
+        format: "+238 ... ....",
+        name: "Cape Verde",
+        continent: "Africa",
+        region: "Western Africa",
+    },
+    Country {
+        code: "+599",
+        flag: "\u{1F1E7}\u{1F1F6}",
+        format: "+599 ... ....",
+        name: "Caribbean Netherlands",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+1345",
+        flag: "\u{1F1F0}\u{1F1FE}",
+        format: "+1345 ... ....",
+        name: "Cayman Islands",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+236",
+        flag: "\u{1F1E8}\u{1F1EB}",
+        format: "+236 ... .. ..",
+        name: "Central African Republic",
+        continent: "Africa",
+        region: "Middle Africa",
+    },
+    Country {
+        code: "+235",
+        flag: "\u{1F1F9}\u{1F1E9}",
+        format: "+235 ... ....",
+        name: "Chad",
+        continent: "Africa",
+        region: "Middle Africa",
+    },
+    Country {
+        code: "+56",
+        flag: "\u{1F1E8}\u{1F1F1}",
+        format: "+56 ... .......",
+        name: "Chile",
+        continent: "America",
+        region: "South America",
+    },
+    Country {
+        code: "+86",
+        flag: "\u{1F1E8}\u{1F1F3}",
+        format: "+86 .. .... ....",
+        name: "China",
+        continent: "Asia",
+        region: "Eastern Asia",
+    },
+    Country {
+        code: "+61",
+        flag: "\u{1F1E8}\u{1F1FD}",
+        format: "+61 .. ... ...",
+        name: "Christmas Island",
+        continent: "Oceania",
+        region: "Australia",
+    },
+    Country {
+        code: "+61",
+        flag: "\u{1F1E8}\u{1F1E8}",
+        format: "+61 .. ... ...",
+        name: "Cocos Islands",
+        continent: "Oceania",
+        region: "Australia",
+    },
+    Country {
+        code: "+57",
+        flag: "\u{1F1E8}\u{1F1F4}",
+        format: "+57 ... .......",
+        name: "Colombia",
+        continent: "America",
+        region: "South America",
+    },
+    Country {
+        code: "+269",
+        flag: "\u{1F1F0}\u{1F1F2}",
+        format: "+269 ... ....",
+        name: "Comoros",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+242",
+        flag: "\u{1F1E8}\u{1F1EC}",
+        format: "+242 ... .. ..",
+        name: "Congo",
+        continent: "Africa",
+        region: "Middle Africa",
+    },
+    Country {
+        code: "+243",
+        flag: "\u{1F1E8}\u{1F1E9}",
+        format: "+243 ... ......",
+        name: "Congo (DRC)",
+        continent: "Africa",
+        region: "Middle Africa",
+    },
+    Country {
+        code: "+682",
+        flag: "\u{1F1E8}\u{1F1F0}",
+        format: "+682 ... ....",
+        name: "Cook Islands",
+        continent: "Oceania",
+        region: "Polynesia",
+    },
+    Country {
+        code: "+506",
+        flag: "\u{1F1E8}\u{1F1F7}",
+        format: "+506 ... ....",
+        name: "Costa Rica",
+        continent: "America",
+        region: "Central America",
+    },
+    Country {
+        code: "+225",
+        flag: "\u{1F1E8}\u{1F1EE}",
+        format: "+225 ... .. ..",
+        name: "C\u{F4}te d'Ivoire",
+        continent: "Africa",
+        region: "Western Africa",
+    },
+    Country {
+        code: "+385",
+        flag: "\u{1F1ED}\u{1F1F7}",
+        format: "+385 ... ....",
+        name: "Croatia",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+53",
+        flag: "\u{1F1E8}\u{1F1FA}",
+        format: "+53 ... .......",
+        name: "Cuba",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+599",
+        flag: "\u{1F1E7}\u{1F1F6}",
+        format: "+599 ... ....",
+        name: "Cura\u{E7}ao",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+357",
+        flag: "\u{1F1E8}\u{1F1FE}",
+        format: "+357 ... ....",
+        name: "Cyprus",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+420",
+        flag: "\u{1F1E8}\u{1F1FF}",
+        format: "+420 ... ....",
+        name: "Czech Republic",
+        continent: "Europe",
+        region: "Eastern Europe",
+    },
+    Country {
+        code: "+45",
+        flag: "\u{1F1E9}\u{1F1F0}",
+        format: "+45 .. .. .. ..",
+        name: "Denmark",
+        continent: "Europe",
+        region: "Northern Europe",
+    },
+    Country {
+        code: "+246",
+        flag: "\u{1F1E9}\u{1F1EC}",
+        format: "+246 ... ....",
+        name: "Diego Garcia",
+        continent: "Africa",
+        region: "Indian Ocean",
+    },
+    Country {
+        code: "+253",
+        flag: "\u{1F1E9}\u{1F1EF}",
+        format: "+253 ... ....",
+        name: "Djibouti",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+1767",
+        flag: "\u{1F1E9}\u{1F1F2}",
+        format: "+1767 ... ....",
+        name: "Dominica",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+1",
+        flag: "\u{1F1E9}\u{1F1F4}",
+        format: "+1 ... ... ....",
+        name: "Dominican Republic",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+593",
+        flag: "\u{1F1EA}\u{1F1E8}",
+        format: "+593 ... ....",
+        name: "Ecuador",
+        continent: "America",
+        region: "South America",
+    },
+    Country {
+        code: "+20",
+        flag: "\u{1F1EA}\u{1F1EC}",
+        format: "+20 ... .......",
+        name: "Egypt",
+        continent: "Africa",
+        region: "Northern Africa",
+    },
+    Country {
+        code: "+503",
+        flag: "\u{1F1F8}\u{1F1FB}",
+        format: "+503 ... ....",
+        name: "El Salvador",
+        continent: "America",
+        region: "Central America",
+    },
+    Country {
+        code: "+240",
+        flag: "\u{1F1EC}\u{1F1F6}",
+        format: "+240 ... ....",
+        name: "Equatorial Guinea",
+        continent: "Africa",
+        region: "Middle Africa",
+    },
+    Country {
+        code: "+291",
+        flag: "\u{1F1EA}\u{1F1F7}",
+        format: "+291 ... ....",
+        name: "Eritrea",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+372",
+        flag: "\u{1F1EA}\u{1F1EA}",
+        format: "+372 ... ....",
+        name: "Estonia",
+        continent: "Europe",
+        region: "Northern Europe",
+    },
+    Country {
+        code: "+251",
+        flag: "\u{1F1EA}\u{1F1F9}",
+        format: "+251 ... ....",
+        name: "Ethiopia",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+500",
+        flag: "\u{1F1EB}\u{1F1F0}",
+        format: "+500 ... ....",
+        name: "Falkland Islands",
+        continent: "America",
+        region: "South America",
+    },
+    Country {
+        code: "+298",
+        flag: "\u{1F1EB}\u{1F1F4}",
+        format: "+298 ... ....",
+        name: "Faroe Islands",
+        continent: "Europe",
+        region: "Northern Europe",
+    },
+    Country {
+        code: "+679",
+        flag: "\u{1F1EB}\u{1F1EF}",
+        format: "+679 ... ....",
+        name: "Fiji",
+        continent: "Oceania",
+        region: "Melanesia",
+    },
+    Country {
+        code: "+358",
+        flag: "\u{1F1EB}\u{1F1EE}",
+        format: "+358 ... .. ..",
+        name: "Finland",
+        continent: "Europe",
+        region: "Northern Europe",
+    },
+    Country {
+        code: "+33",
+        flag: "\u{1F1EB}\u{1F1F7}",
+        format: "+33 .. .. .. ..",
+        name: "France",
+        continent: "Europe",
+        region: "Western Europe",
+    },
+    Country {
+        code: "+596",
+        flag: "\u{1F1F2}\u{1F1EB}",
+        format: "+596 ... ....",
+        name: "French Antilles",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+594",
+        flag: "\u{1F1EC}\u{1F1EB}",
+        format: "+594 ... ....",
+        name: "French Guiana",
+        continent: "America",
+        region: "South America",
+    },
+    Country {
+        code: "+689",
+        flag: "\u{1F1F5}\u{1F1EB}",
+        format: "+689 ... ....",
+        name: "French Polynesia",
+        continent: "Oceania",
+        region: "Polynesia",
+    },
+    Country {
+        code: "+241",
+        flag: "\u{1F1EC}\u{1F1E6}",
+        format: "+241 ... ....",
+        name: "Gabon",
+        continent: "Africa",
+        region: "Middle Africa",
+    },
+    Country {
+        code: "+220",
+        flag: "\u{1F1EC}\u{1F1F2}",
+        format: "+220 ... ....",
+        name: "Gambia",
+        continent: "Africa",
+        region: "Western Africa",
+    },
+    Country {
+        code: "+995",
+        flag: "\u{1F1EC}\u{1F1EA}",
+        format: "+995 ... ....",
+        name: "Georgia",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+49",
+        flag: "\u{1F1E9}\u{1F1EA}",
+        format: "+49 .. ... ...",
+        name: "Germany",
+        continent: "Europe",
+        region: "Western Europe",
+    },
+    Country {
+        code: "+233",
+        flag: "\u{1F1EC}\u{1F1ED}",
+        format: "+233 ... ....",
+        name: "Ghana",
+        continent: "Africa",
+        region: "Western Africa",
+    },
+    Country {
+        code: "+350",
+        flag: "\u{1F1EC}\u{1F1EE}",
+        format: "+350 ... ....",
+        name: "Gibraltar",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+30",
+        flag: "\u{1F1EC}\u{1F1F7}",
+        format: "+30 .. ... ....",
+        name: "Greece",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+299",
+        flag: "\u{1F1EC}\u{1F1F1}",
+        format: "+299 ... ....",
+        name: "Greenland",
+        continent: "America",
+        region: "Northern America",
+    },
+    Country {
+        code: "+1473",
+        flag: "\u{1F1EC}\u{1F1E9}",
+        format: "+1473 ... ....",
+        name: "Grenada",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+590",
+        flag: "\u{1F1EC}\u{1F1F5}",
+        format: "+590 ... ....",
+        name: "Guadeloupe",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+1671",
+        flag: "\u{1F1EC}\u{1F1FA}",
+        format: "+1671 ... ....",
+        name: "Guam",
+        continent: "Oceania",
+        region: "Micronesia",
+    },
+    Country {
+        code: "+502",
+        flag: "\u{1F1EC}\u{1F1F9}",
+        format: "+502 ... ....",
+        name: "Guatemala",
+        continent: "America",
+        region: "Central America",
+    },
+    Country {
+        code: "+44",
+        flag: "\u{1F1EC}\u{1F1EC}",
+        format: "+44 .. .... ..",
+        name: "Guernsey",
+        continent: "Europe",
+        region: "Northern Europe",
+    },
+    Country {
+        code: "+224",
+        flag: "\u{1F1EC}\u{1F1F3}",
+        format: "+224 ... ....",
+        name: "Guinea",
+        continent: "Africa",
+        region: "Western Africa",
+    },
+    Country {
+        code: "+245",
+        flag: "\u{1F1EC}\u{1F1FC}",
+        format: "+245 ... ....",
+        name: "Guinea-Bissau",
+        continent: "Africa",
+        region: "Western Africa",
+    },
+    Country {
+        code: "+592",
+        flag: "\u{1F1EC}\u{1F1FE}",
+        format: "+592 ... ....",
+        name: "Guyana",
+        continent: "America",
+        region: "South America",
+    },
+    Country {
+        code: "+509",
+        flag: "\u{1F1ED}\u{1F1F9}",
+        format: "+509 ... ....",
+        name: "Haiti",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+504",
+        flag: "\u{1F1ED}\u{1F1F3}",
+        format: "+504 ... ....",
+        name: "Honduras",
+        continent: "America",
+        region: "Central America",
+    },
+    Country {
+        code: "+852",
+        flag: "\u{1F1ED}\u{1F1F0}",
+        format: "+852 ... ....",
+        name: "Hong Kong",
+        continent: "Asia",
+        region: "Eastern Asia",
+    },
+    Country {
+        code: "+36",
+        flag: "\u{1F1ED}\u{1F1FA}",
+        format: "+36 .. .......",
+        name: "Hungary",
+        continent: "Europe",
+        region: "Eastern Europe",
+    },
+    Country {
+        code: "+354",
+        flag: "\u{1F1EE}\u{1F1F8}",
+        format: "+354 ... ....",
+        name: "Iceland",
+        continent: "Europe",
+        region: "Northern Europe",
+    },
+    Country {
+        code: "+91",
+        flag: "\u{1F1EE}\u{1F1F3}",
+        format: "+91 .. ... ....",
+        name: "India",
+        continent: "Asia",
+        region: "Southern Asia",
+    },
+    Country {
+        code: "+62",
+        flag: "\u{1F1EE}\u{1F1E9}",
+        format: "+62 .. .......",
+        name: "Indonesia",
+        continent: "Asia",
+        region: "South-Eastern Asia",
+    },
+    Country {
+        code: "+98",
+        flag: "\u{1F1EE}\u{1F1F7}",
+        format: "+98 .. .... ...",
+        name: "Iran",
+        continent: "Asia",
+        region: "Southern Asia",
+    },
+    Country {
+        code: "+964",
+        flag: "\u{1F1EE}\u{1F1F6}",
+        format: "+964 ... ....",
+        name: "Iraq",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+353",
+        flag: "\u{1F1EE}\u{1F1EA}",
+        format: "+353 ... ....",
+        name: "Ireland",
+        continent: "Europe",
+        region: "Northern Europe",
+    },
+    Country {
+        code: "+44",
+        flag: "\u{1F1EE}\u{1F1F2}",
+        format: "+44 .. .... ..",
+        name: "Isle of Man",
+        continent: "Europe",
+        region: "Northern Europe",
+    },
+    Country {
+        code: "+972",
+        flag: "\u{1F1EE}\u{1F1F1}",
+        format: "+972 ... ....",
+        name: "Israel",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+39",
+        flag: "\u{1F1EE}\u{1F1F9}",
+        format: "+39 .. ... ....",
+        name: "Italy",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+1876",
+        flag: "\u{1F1EF}\u{1F1F2}",
+        format: "+1876 ... ....",
+        name: "Jamaica",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+81",
+        flag: "\u{1F1EF}\u{1F1F5}",
+        format: "+81 .. .... ....",
+        name: "Japan",
+        continent: "Asia",
+        region: "Eastern Asia",
+    },
+    Country {
+        code: "+44",
+        flag: "\u{1F1EF}\u{1F1EA}",
+        format: "+44 .. .... ..",
+        name: "Jersey",
+        continent: "Europe",
+        region: "Northern Europe",
+    },
+    Country {
+        code: "+962",
+        flag: "\u{1F1EF}\u{1F1F4}",
+        format: "+962 ... ....",
+        name: "Jordan",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+7",
+        flag: "\u{1F1F0}\u{1F1FF}",
+        format: "+7 .. ... ......",
+        name: "Kazakhstan",
+        continent: "Asia",
+        region: "Central Asia",
+    },
+    Country {
+        code: "+254",
+        flag: "\u{1F1F0}\u{1F1EA}",
+        format: "+254 ... ....",
+        name: "Kenya",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+686",
+        flag: "\u{1F1F0}\u{1F1EE}",
+        format: "+686 ... ....",
+        name: "Kiribati",
+        continent: "Oceania",
+        region: "Micronesia",
+    },
+    Country {
+        code: "+850",
+        flag: "\u{1F1F0}\u{1F1F5}",
+        format: "+850 ... ....",
+        name: "North Korea",
+        continent: "Asia",
+        region: "Eastern Asia",
+    },
+    Country {
+        code: "+82",
+        flag: "\u{1F1F0}\u{1F1F7}",
+        format: "+82 .. ... ....",
+        name: "South Korea",
+        continent: "Asia",
+        region: "Eastern Asia",
+    },
+    Country {
+        code: "+383",
+        flag: "\u{1F1FD}\u{1F1F0}",
+        format: "+383 ... ....",
+        name: "Kosovo",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+965",
+        flag: "\u{1F1F0}\u{1F1FC}",
+        format: "+965 ... ....",
+        name: "Kuwait",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+996",
+        flag: "\u{1F1F0}\u{1F1EC}",
+        format: "+996 ... ....",
+        name: "Kyrgyzstan",
+        continent: "Asia",
+        region: "Central Asia",
+    },
+    Country {
+        code: "+856",
+        flag: "\u{1F1F1}\u{1F1E6}",
+        format: "+856 ... ....",
+        name: "Laos",
+        continent: "Asia",
+        region: "South-Eastern Asia",
+    },
+    Country {
+        code: "+371",
+        flag: "\u{1F1F1}\u{1F1FB}",
+        format: "+371 ... ....",
+        name: "Latvia",
+        continent: "Europe",
+        region: "Northern Europe",
+    },
+    Country {
+        code: "+961",
+        flag: "\u{1F1F1}\u{1F1E7}",
+        format: "+961 ... ....",
+        name: "Lebanon",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+266",
+        flag: "\u{1F1F1}\u{1F1F8}",
+        format: "+266 ... ....",
+        name: "Lesotho",
+        continent: "Africa",
+        region: "Southern Africa",
+    },
+    Country {
+        code: "+231",
+        flag: "\u{1F1F1}\u{1F1F7}",
+        format: "+231 ... ....",
+        name: "Liberia",
+        continent: "Africa",
+        region: "Western Africa",
+    },
+    Country {
+        code: "+218",
+        flag: "\u{1F1F1}\u{1F1FE}",
+        format: "+218 ... ....",
+        name: "Libya",
+        continent: "Africa",
+        region: "Northern Africa",
+    },
+    Country {
+        code: "+423",
+        flag: "\u{1F1F1}\u{1F1EE}",
+        format: "+423 ... ....",
+        name: "Liechtenstein",
+        continent: "Europe",
+        region: "Western Europe",
+    },
+    Country {
+        code: "+370",
+        flag: "\u{1F1F1}\u{1F1F9}",
+        format: "+370 ... ....",
+        name: "Lithuania",
+        continent: "Europe",
+        region: "Northern Europe",
+    },
+    Country {
+        code: "+352",
+        flag: "\u{1F1F1}\u{1F1FA}",
+        format: "+352 ... ....",
+        name: "Luxembourg",
+        continent: "Europe",
+        region: "Western Europe",
+    },
+    Country {
+        code: "+853",
+        flag: "\u{1F1F2}\u{1F1F4}",
+        format: "+853 ... ....",
+        name: "Macau",
+        continent: "Asia",
+        region: "Eastern Asia",
+    },
+    Country {
+        code: "+389",
+        flag: "\u{1F1F2}\u{1F1F0}",
+        format: "+389 ... ....",
+        name: "North Macedonia",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+261",
+        flag: "\u{1F1F2}\u{1F1EC}",
+        format: "+261 ... ....",
+        name: "Madagascar",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+265",
+        flag: "\u{1F1F2}\u{1F1FC}",
+        format: "+265 ... ....",
+        name: "Malawi",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+60",
+        flag: "\u{1F1F2}\u{1F1FE}",
+        format: "+60 .. ... ...",
+        name: "Malaysia",
+        continent: "Asia",
+        region: "South-Eastern Asia",
+    },
+    Country {
+        code: "+960",
+        flag: "\u{1F1F2}\u{1F1FB}",
+        format: "+960 ... ....",
+        name: "Maldives",
+        continent: "Asia",
+        region: "Southern Asia",
+    },
+    Country {
+        code: "+223",
+        flag: "\u{1F1F2}\u{1F1F1}",
+        format: "+223 ... ....",
+        name: "Mali",
+        continent: "Africa",
+        region: "Western Africa",
+    },
+    Country {
+        code: "+356",
+        flag: "\u{1F1F2}\u{1F1F9}",
+        format: "+356 ... ....",
+        name: "Malta",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+692",
+        flag: "\u{1F1F2}\u{1F1ED}",
+        format: "+692 ... ....",
+        name: "Marshall Islands",
+        continent: "Oceania",
+        region: "Micronesia",
+    },
+    Country {
+        code: "+596",
+        flag: "\u{1F1F2}\u{1F1F6}",
+        format: "+596 ... ....",
+        name: "Martinique",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+222",
+        flag: "\u{1F1F2}\u{1F1F7}",
+        format: "+222 ... ....",
+        name: "Mauritania",
+        continent: "Africa",
+        region: "Western Africa",
+    },
+    Country {
+        code: "+230",
+        flag: "\u{1F1F2}\u{1F1FA}",
+        format: "+230 ... ....",
+        name: "Mauritius",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+262",
+        flag: "\u{1F1FE}\u{1F1F9}",
+        format: "+262 ... ....",
+        name: "Mayotte",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+52",
+        flag: "\u{1F1F2}\u{1F1FD}",
+        format: "+52 .. .... ....",
+        name: "Mexico",
+        continent: "America",
+        region: "Central America",
+    },
+    Country {
+        code: "+691",
+        flag: "\u{1F1EB}\u{1F1F2}",
+        format: "+691 ... ....",
+        name: "Micronesia",
+        continent: "Oceania",
+        region: "Micronesia",
+    },
+    Country {
+        code: "+373",
+        flag: "\u{1F1F2}\u{1F1E9}",
+        format: "+373 ... ....",
+        name: "Moldova",
+        continent: "Europe",
+        region: "Eastern Europe",
+    },
+    Country {
+        code: "+377",
+        flag: "\u{1F1F2}\u{1F1E8}",
+        format: "+377 ... ....",
+        name: "Monaco",
+        continent: "Europe",
+        region: "Western Europe",
+    },
+    Country {
+        code: "+976",
+        flag: "\u{1F1F2}\u{1F1F3}",
+        format: "+976 ... ....",
+        name: "Mongolia",
+        continent: "Asia",
+        region: "Eastern Asia",
+    },
+    Country {
+        code: "+382",
+        flag: "\u{1F1F2}\u{1F1EA}",
+        format: "+382 ... ....",
+        name: "Montenegro",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+1664",
+        flag: "\u{1F1F2}\u{1F1F8}",
+        format: "+1664 ... ....",
+        name: "Montserrat",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+212",
+        flag: "\u{1F1EA}\u{1F1ED}",
+        format: "+212 ... ....",
+        name: "Morocco",
+        continent: "Africa",
+        region: "Northern Africa",
+    },
+    Country {
+        code: "+258",
+        flag: "\u{1F1F2}\u{1F1FF}",
+        format: "+258 ... ....",
+        name: "Mozambique",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+95",
+        flag: "\u{1F1F2}\u{1F1F2}",
+        format: "+95 .. .... ....",
+        name: "Myanmar",
+        continent: "Asia",
+        region: "South-Eastern Asia",
+    },
+    Country {
+        code: "+264",
+        flag: "\u{1F1F3}\u{1F1E6}",
+        format: "+264 ... ....",
+        name: "Namibia",
+        continent: "Africa",
+        region: "Southern Africa",
+    },
+    Country {
+        code: "+674",
+        flag: "\u{1F1F3}\u{1F1F7}",
+        format: "+674 ... ....",
+        name: "Nauru",
+        continent: "Oceania",
+        region: "Micronesia",
+    },
+    Country {
+        code: "+977",
+        flag: "\u{1F1F3}\u{1F1F5}",
+        format: "+977 ... ....",
+        name: "Nepal",
+        continent: "Asia",
+        region: "Southern Asia",
+    },
+    Country {
+        code: "+31",
+        flag: "\u{1F1F3}\u{1F1F1}",
+        format: "+31 .. ... ..",
+        name: "Netherlands",
+        continent: "Europe",
+        region: "Western Europe",
+    },
+    Country {
+        code: "+599",
+        flag: "\u{1F1E7}\u{1F1F6}",
+        format: "+599 ... ....",
+        name: "Netherlands Antilles",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+687",
+        flag: "\u{1F1F3}\u{1F1E8}",
+        format: "+687 ... ....",
+        name: "New Caledonia",
+        continent: "Oceania",
+        region: "Melanesia",
+    },
+    Country {
+        code: "+64",
+        flag: "\u{1F1F3}\u{1F1FF}",
+        format: "+64 .. ... ....",
+        name: "New Zealand",
+        continent: "Oceania",
+        region: "Australia",
+    },
+    Country {
+        code: "+505",
+        flag: "\u{1F1F3}\u{1F1EE}",
+        format: "+505 ... ....",
+        name: "Nicaragua",
+        continent: "America",
+        region: "Central America",
+    },
+    Country {
+        code: "+227",
+        flag: "\u{1F1F3}\u{1F1EA}",
+        format: "+227 ... ....",
+        name: "Niger",
+        continent: "Africa",
+        region: "Western Africa",
+    },
+    Country {
+        code: "+234",
+        flag: "\u{1F1F3}\u{1F1EC}",
+        format: "+234 ... ....",
+        name: "Nigeria",
+        continent: "Africa",
+        region: "Western Africa",
+    },
+    Country {
+        code: "+683",
+        flag: "\u{1F1F3}\u{1F1FA}",
+        format: "+683 ... ....",
+        name: "Niue",
+        continent: "Oceania",
+        region: "Polynesia",
+    },
+    Country {
+        code: "+672",
+        flag: "\u{1F1F3}\u{1F1EB}",
+        format: "+672 ... ....",
+        name: "Norfolk Island",
+        continent: "Oceania",
+        region: "Australia",
+    },
+    Country {
+        code: "+1670",
+        flag: "\u{1F1F2}\u{1F1F5}",
+        format: "+1670 ... ....",
+        name: "Northern Mariana Islands",
+        continent: "Oceania",
+        region: "Micronesia",
+    },
+    Country {
+        code: "+47",
+        flag: "\u{1F1F3}\u{1F1F4}",
+        format: "+47 .. ... ....",
+        name: "Norway",
+        continent: "Europe",
+        region: "Northern Europe",
+    },
+    Country {
+        code: "+968",
+        flag: "\u{1F1F4}\u{1F1F2}",
+        format: "+968 ... ....",
+        name: "Oman",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+92",
+        flag: "\u{1F1F5}\u{1F1F0}",
+        format: "+92 .. ... ..",
+        name: "Pakistan",
+        continent: "Asia",
+        region: "Southern Asia",
+    },
+    Country {
+        code: "+680",
+        flag: "\u{1F1F5}\u{1F1FC}",
+        format: "+680 ... ....",
+        name: "Palau",
+        continent: "Oceania",
+        region: "Micronesia",
+    },
+    Country {
+        code: "+970",
+        flag: "\u{1F1F5}\u{1F1F8}",
+        format: "+970 ... ....",
+        name: "Palestinian Territories",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+507",
+        flag: "\u{1F1F5}\u{1F1E6}",
+        format: "+507 ... ....",
+        name: "Panama",
+        continent: "America",
+        region: "Central America",
+    },
+    Country {
+        code: "+675",
+        flag: "\u{1F1F5}\u{1F1EC}",
+        format: "+675 ... ....",
+        name: "Papua New Guinea",
+        continent: "Oceania",
+        region: "Melanesia",
+    },
+    Country {
+        code: "+595",
+        flag: "\u{1F1F5}\u{1F1FE}",
+        format: "+595 ... ....",
+        name: "Paraguay",
+        continent: "South America",
+        region: "South America",
+    },
+    Country {
+        code: "+51",
+        flag: "\u{1F1F5}\u{1F1EA}",
+        format: "+51 .. .... ....",
+        name: "Peru",
+        continent: "America",
+        region: "South America",
+    },
+    Country {
+        code: "+63",
+        flag: "\u{1F1F5}\u{1F1ED}",
+        format: "+63 .. ... ....",
+        name: "Philippines",
+        continent: "Asia",
+        region: "South-Eastern Asia",
+    },
+    Country {
+        code: "+48",
+        flag: "\u{1F1F5}\u{1F1F1}",
+        format: "+48 .. .... ....",
+        name: "Poland",
+        continent: "Europe",
+        region: "Eastern Europe",
+    },
+    Country {
+        code: "+351",
+        flag: "\u{1F1F5}\u{1F1F9}",
+        format: "+351 ... ....",
+        name: "Portugal",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+1",
+        flag: "\u{1F1F5}\u{1F1F7}",
+        format: "+1 ... ... ....",
+        name: "Puerto Rico",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+974",
+        flag: "\u{1F1F6}\u{1F1E6}",
+        format: "+974 ... ....",
+        name: "Qatar",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+262",
+        flag: "\u{1F1F7}\u{1F1EA}",
+        format: "+262 ... ....",
+        name: "R\u{E9}union",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+40",
+        flag: "\u{1F1F7}\u{1F1F4}",
+        format: "+40 .. .... ....",
+        name: "Romania",
+        continent: "Europe",
+        region: "Eastern Europe",
+    },
+    Country {
+        code: "+7",
+        flag: "\u{1F1F7}\u{1F1FA}",
+        format: "+7 .. ... ......",
+        name: "Russia",
+        continent: "Europe",
+        region: "Eastern Europe",
+    },
+    Country {
+        code: "+250",
+        flag: "\u{1F1F7}\u{1F1FC}",
+        format: "+250 ... ....",
+        name: "Rwanda",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+290",
+        flag: "\u{1F1F8}\u{1F1ED}",
+        format: "+290 ... ....",
+        name: "Saint Helena",
+        continent: "Africa",
+        region: "Atlantic",
+    },
+    Country {
+        code: "+1869",
+        flag: "\u{1F1F0}\u{1F1F3}",
+        format: "+1869 ... ....",
+        name: "Saint Kitts and Nevis",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+1758",
+        flag: "\u{1F1F1}\u{1F1E8}",
+        format: "+1758 ... ....",
+        name: "Saint Lucia",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+590",
+        flag: "\u{1F1EC}\u{1F1F5}",
+        format: "+590 ... ....",
+        name: "Saint Pierre and Miquelon",
+        continent: "America",
+        region: "Northern America",
+    },
+    Country {
+        code: "+1784",
+        flag: "\u{1F1FB}\u{1F1E8}",
+        format: "+1784 ... ....",
+        name: "Saint Vincent and the Grenadines",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+685",
+        flag: "\u{1F1FC}\u{1F1F8}",
+        format: "+685 ... ....",
+        name: "Samoa",
+        continent: "Oceania",
+        region: "Polynesia",
+    },
+    Country {
+        code: "+378",
+        flag: "\u{1F1F8}\u{1F1F2}",
+        format: "+378 ... ....",
+        name: "San Marino",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+239",
+        flag: "\u{1F1F8}\u{1F1F9}",
+        format: "+239 ... ....",
+        name: "S\u{E3}o Tom\u{E9} and Pr\u{ED}ncipe",
+        continent: "Africa",
+        region: "Middle Africa",
+    },
+    Country {
+        code: "+966",
+        flag: "\u{1F1F8}\u{1F1E6}",
+        format: "+966 ... ....",
+        name: "Saudi Arabia",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+221",
+        flag: "\u{1F1F8}\u{1F1F3}",
+        format: "+221 ... ....",
+        name: "Senegal",
+        continent: "Africa",
+        region: "Western Africa",
+    },
+    Country {
+        code: "+381",
+        flag: "\u{1F1F7}\u{1F1F8}",
+        format: "+381 ... ....",
+        name: "Serbia",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+248",
+        flag: "\u{1F1F8}\u{1F1E8}",
+        format: "+248 ... ....",
+        name: "Seychelles",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+232",
+        flag: "\u{1F1F8}\u{1F1F1}",
+        format: "+232 ... ....",
+        name: "Sierra Leone",
+        continent: "Africa",
+        region: "Western Africa",
+    },
+    Country {
+        code: "+65",
+        flag: "\u{1F1F8}\u{1F1EC}",
+        format: "+65 ... ....",
+        name: "Singapore",
+        continent: "Asia",
+        region: "South-Eastern Asia",
+    },
+    Country {
+        code: "+1721",
+        flag: "\u{1F1F8}\u{1F1FD}",
+        format: "+1721 ... ....",
+        name: "Sint Maarten",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+421",
+        flag: "\u{1F1F8}\u{1F1F0}",
+        format: "+421 ... ....",
+        name: "Slovakia",
+        continent: "Europe",
+        region: "Eastern Europe",
+    },
+    Country {
+        code: "+386",
+        flag: "\u{1F1F8}\u{1F1EE}",
+        format: "+386 ... ....",
+        name: "Slovenia",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+677",
+        flag: "\u{1F1F8}\u{1F1E7}",
+        format: "+677 ... ....",
+        name: "Solomon Islands",
+        continent: "Oceania",
+        region: "Melanesia",
+    },
+    Country {
+        code: "+252",
+        flag: "\u{1F1F8}\u{1F1F4}",
+        format: "+252 ... ....",
+        name: "Somalia",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+27",
+        flag: "\u{1F1FF}\u{1F1E6}",
+        format: "+27 .. .... ....",
+        name: "South Africa",
+        continent: "Africa",
+        region: "Southern Africa",
+    },
+    Country {
+        code: "+211",
+        flag: "\u{1F1F8}\u{1F1F8}",
+        format: "+211 ... ....",
+        name: "South Sudan",
+        continent: "Africa",
+        region: "Middle Africa",
+    },
+    Country {
+        code: "+34",
+        flag: "\u{1F1EA}\u{1F1F8}",
+        format: "+34 .. ... ....",
+        name: "Spain",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+94",
+        flag: "\u{1F1F1}\u{1F1F0}",
+        format: "+94 .. ... ....",
+        name: "Sri Lanka",
+        continent: "Asia",
+        region: "Southern Asia",
+    },
+    Country {
+        code: "+249",
+        flag: "\u{1F1F8}\u{1F1E9}",
+        format: "+249 ... ....",
+        name: "Sudan",
+        continent: "Africa",
+        region: "Northern Africa",
+    },
+    Country {
+        code: "+597",
+        flag: "\u{1F1F8}\u{1F1F7}",
+        format: "+597 ... ....",
+        name: "Suriname",
+        continent: "America",
+        region: "South America",
+    },
+    Country {
+        code: "+47",
+        flag: "\u{1F1F8}\u{1F1EF}",
+        format: "+47 .. ... ....",
+        name: "Svalbard and Jan Mayen",
+        continent: "Europe",
+        region: "Northern Europe",
+    },
+    Country {
+        code: "+268",
+        flag: "\u{1F1F8}\u{1F1FF}",
+        format: "+268 ... ....",
+        name: "Swaziland",
+        continent: "Africa",
+        region: "Southern Africa",
+    },
+    Country {
+        code: "+46",
+        flag: "\u{1F1F8}\u{1F1EA}",
+        format: "+46 .. ... ....",
+        name: "Sweden",
+        continent: "Europe",
+        region: "Northern Europe",
+    },
+    Country {
+        code: "+41",
+        flag: "\u{1F1E8}\u{1F1ED}",
+        format: "+41 .. ... ....",
+        name: "Switzerland",
+        continent: "Europe",
+        region: "Western Europe",
+    },
+    Country {
+        code: "+963",
+        flag: "\u{1F1F8}\u{1F1FE}",
+        format: "+963 ... ....",
+        name: "Syria",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+886",
+        flag: "\u{1F1F9}\u{1F1FC}",
+        format: "+886 ... ....",
+        name: "Taiwan",
+        continent: "Asia",
+        region: "Eastern Asia",
+    },
+    Country {
+        code: "+992",
+        flag: "\u{1F1F9}\u{1F1EF}",
+        format: "+992 ... ....",
+        name: "Tajikistan",
+        continent: "Asia",
+        region: "Central Asia",
+    },
+    Country {
+        code: "+255",
+        flag: "\u{1F1F9}\u{1F1FF}",
+        format: "+255 ... ....",
+        name: "Tanzania",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+66",
+        flag: "\u{1F1F9}\u{1F1ED}",
+        format: "+66 .. ... ....",
+        name: "Thailand",
+        continent: "Asia",
+        region: "South-Eastern Asia",
+    },
+    Country {
+        code: "+670",
+        flag: "\u{1F1F9}\u{1F1F1}",
+        format: "+670 ... ....",
+        name: "Timor-Leste",
+        continent: "Asia",
+        region: "South-Eastern Asia",
+    },
+    Country {
+        code: "+228",
+        flag: "\u{1F1F9}\u{1F1EC}",
+        format: "+228 ... ....",
+        name: "Togo",
+        continent: "Africa",
+        region: "Western Africa",
+    },
+    Country {
+        code: "+690",
+        flag: "\u{1F1F9}\u{1F1F0}",
+        format: "+690 ... ....",
+        name: "Tokelau",
+        continent: "Oceania",
+        region: "Polynesia",
+    },
+    Country {
+        code: "+676",
+        flag: "\u{1F1F9}\u{1F1F4}",
+        format: "+676 ... ....",
+        name: "Tonga",
+        continent: "Oceania",
+        region: "Polynesia",
+    },
+    Country {
+        code: "+1868",
+        flag: "\u{1F1F9}\u{1F1F9}",
+        format: "+1868 ... ....",
+        name: "Trinidad and Tobago",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+216",
+        flag: "\u{1F1F9}\u{1F1F3}",
+        format: "+216 ... ....",
+        name: "Tunisia",
+        continent: "Africa",
+        region: "Northern Africa",
+    },
+    Country {
+        code: "+90",
+        flag: "\u{1F1F9}\u{1F1F7}",
+        format: "+90 .. ... ....",
+        name: "Turkey",
+        continent: "Europe",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+993",
+        flag: "\u{1F1F9}\u{1F1F2}",
+        format: "+993 ... ....",
+        name: "Turkmenistan",
+        continent: "Asia",
+        region: "Central Asia",
+    },
+    Country {
+        code: "+1649",
+        flag: "\u{1F1F9}\u{1F1E8}",
+        format: "+1649 ... ....",
+        name: "Turks and Caicos Islands",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+688",
+        flag: "\u{1F1F9}\u{1F1FB}",
+        format: "+688 ... ....",
+        name: "Tuvalu",
+        continent: "Oceania",
+        region: "Polynesia",
+    },
+    Country {
+        code: "+256",
+        flag: "\u{1F1FA}\u{1F1EC}",
+        format: "+256 ... ....",
+        name: "Uganda",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+380",
+        flag: "\u{1F1FA}\u{1F1E6}",
+        format: "+380 ... ....",
+        name: "Ukraine",
+        continent: "Europe",
+        region: "Eastern Europe",
+    },
+    Country {
+        code: "+971",
+        flag: "\u{1F1E6}\u{1F1EA}",
+        format: "+971 ... ....",
+        name: "United Arab Emirates",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+44",
+        flag: "\u{1F1EC}\u{1F1E7}",
+        format: "+44 .. .... ..",
+        name: "United Kingdom",
+        continent: "Europe",
+        region: "Northern Europe",
+    },
+    Country {
+        code: "+1",
+        flag: "\u{1F1FA}\u{1F1F8}",
+        format: "+1 ... ... ....",
+        name: "United States",
+        continent: "America",
+        region: "Northern America",
+    },
+    Country {
+        code: "+598",
+        flag: "\u{1F1FA}\u{1F1FE}",
+        format: "+598 ... ....",
+        name: "Uruguay",
+        continent: "America",
+        region: "South America",
+    },
+    Country {
+        code: "+998",
+        flag: "\u{1F1FA}\u{1F1FF}",
+        format: "+998 ... ....",
+        name: "Uzbekistan",
+        continent: "Asia",
+        region: "Central Asia",
+    },
+    Country {
+        code: "+678",
+        flag: "\u{1F1FB}\u{1F1FA}",
+        format: "+678 ... ....",
+        name: "Vanuatu",
+        continent: "Oceania",
+        region: "Melanesia",
+    },
+    Country {
+        code: "+39",
+        flag: "\u{1F1FB}\u{1F1E6}",
+        format: "+39 .. ... ....",
+        name: "Vatican City",
+        continent: "Europe",
+        region: "Southern Europe",
+    },
+    Country {
+        code: "+58",
+        flag: "\u{1F1FB}\u{1F1EA}",
+        format: "+58 .. .... ....",
+        name: "Venezuela",
+        continent: "America",
+        region: "South America",
+    },
+    Country {
+        code: "+84",
+        flag: "\u{1F1FB}\u{1F1F3}",
+        format: "+84 .. .... ....",
+        name: "Vietnam",
+        continent: "Asia",
+        region: "South-Eastern Asia",
+    },
+    Country {
+        code: "+1284",
+        flag: "\u{1F1FB}\u{1F1EC}",
+        format: "+1284 ... ....",
+        name: "Virgin Islands (UK)",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+1340",
+        flag: "\u{1F1FB}\u{1F1EE}",
+        format: "+1340 ... ....",
+        name: "Virgin Islands (US)",
+        continent: "America",
+        region: "Caribbean",
+    },
+    Country {
+        code: "+681",
+        flag: "\u{1F1FC}\u{1F1EB}",
+        format: "+681 ... ....",
+        name: "Wallis and Futuna",
+        continent: "Oceania",
+        region: "Polynesia",
+    },
+    Country {
+        code: "+212",
+        flag: "\u{1F1EA}\u{1F1ED}",
+        format: "+212 ... ....",
+        name: "Western Sahara",
+        continent: "Africa",
+        region: "Northern Africa",
+    },
+    Country {
+        code: "+967",
+        flag: "\u{1F1FE}\u{1F1EA}",
+        format: "+967 ... ....",
+        name: "Yemen",
+        continent: "Asia",
+        region: "Western Asia",
+    },
+    Country {
+        code: "+260",
+        flag: "\u{1F1FF}\u{1F1F2}",
+        format: "+260 ... ....",
+        name: "Zambia",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
+    Country {
+        code: "+263",
+        flag: "\u{1F1FF}\u{1F1FC}",
+        format: "+263 ... ....",
+        name: "Zimbabwe",
+        continent: "Africa",
+        region: "Eastern Africa",
+    },
 ];
@@ -0,0 +1,60 @@
+use gloo_timers::callback::Timeout;
+use yew::prelude::*;
+
+/// use_debounce
+/// Returns `value` once it has stopped changing for `ms` milliseconds, e.g. to run an expensive check or
+/// a request only after the user pauses typing.
+///
+/// Each change restarts the delay, and the pending update is cancelled when the component unmounts. With
+/// a delay of `0`, `value` is returned as is. Changing the delay settles on the current value.
+///
+/// # Arguments
+/// * `value` - The value to debounce.
+/// * `ms` - The delay, in milliseconds.
+///
+/// # Returns
+/// (T): The last value that stayed unchanged for `ms` milliseconds, initially `value`.
+///
+/// # Examples
+/// ```
+/// use input_yew::use_debounce;
+/// use yew::prelude::*;
+///
+/// #[function_component(Search)]
+/// fn search() -> Html {
+///     let query_handle = use_state(String::default);
+///     let query = use_debounce((*query_handle).clone(), 300);
+///
+///     html! { <p>{ format!("Searching for {}", query) }</p> }
+/// }
+/// ```
+#[hook]
+pub fn use_debounce<T>(value: T, ms: u32) -> T
+where
+    T: Clone + PartialEq + 'static,
+{
+    let debounced_handle = use_state_eq(|| value.clone());
+
+    {
+        // Without a delay the settled value isn't tracked, so it would be stale once a delay is set
+        let debounced_handle = debounced_handle.clone();
+        let value = value.clone();
+        use_effect_with(ms, move |_| debounced_handle.set(value));
+    }
+
+    {
+        let debounced_handle = debounced_handle.clone();
+        use_effect_with((value.clone(), ms), move |(value, ms)| {
+            // Without a delay the value is returned as is, so there's no need to re-render
+            let value = value.clone();
+            let timeout = (*ms > 0).then(|| Timeout::new(*ms, move || debounced_handle.set(value)));
+            move || drop(timeout)
+        });
+    }
+
+    if ms == 0 {
+        value
+    } else {
+        (*debounced_handle).clone()
+    }
+}
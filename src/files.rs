@@ -0,0 +1,40 @@
+use web_sys::{File, FileList};
+
+/// The metadata of a file selected in a file input, reported through `on_files_meta`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileMeta {
+    /// The name of the file, without its path, e.g. "avatar.png".
+    pub name: String,
+    /// The size of the file, in bytes.
+    pub size: f64,
+    /// The MIME type of the file, e.g. "image/png", or an empty string when unknown.
+    pub mime_type: String,
+    /// The last modification time of the file, in milliseconds since the Unix epoch.
+    pub last_modified: f64,
+}
+
+impl From<&File> for FileMeta {
+    fn from(file: &File) -> Self {
+        Self {
+            name: file.name(),
+            size: file.size(),
+            mime_type: file.type_(),
+            last_modified: file.last_modified(),
+        }
+    }
+}
+
+/// files_meta
+/// Reads the metadata of every file in `files`, e.g. to show their names and sizes before uploading.
+///
+/// # Arguments
+/// * `files` - The files selected in a file input.
+///
+/// # Returns
+/// (`Vec<FileMeta>`): The metadata of each file, in selection order.
+pub fn files_meta(files: &FileList) -> Vec<FileMeta> {
+    (0..files.length())
+        .filter_map(|index| files.item(index))
+        .map(|file| FileMeta::from(&file))
+        .collect()
+}
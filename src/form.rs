@@ -0,0 +1,354 @@
+use crate::VISUALLY_HIDDEN_STYLE;
+use std::rc::Rc;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+/// The state a `CustomInput` reports to its enclosing form.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FieldState {
+    /// Whether the field is required.
+    pub required: bool,
+    /// Whether the field is currently valid.
+    pub valid: bool,
+    /// The current value of the field.
+    pub value: String,
+    /// A reference to the input element of the field, used to scroll to and focus it.
+    pub input_ref: NodeRef,
+}
+
+impl FieldState {
+    /// Returns `true` when the field is required, valid and not blank.
+    pub fn is_completed(&self) -> bool {
+        self.required && self.valid && !self.value.trim().is_empty()
+    }
+
+    /// Returns `true` when the field is invalid, or required and blank.
+    pub fn has_error(&self) -> bool {
+        !self.valid || (self.required && self.value.trim().is_empty())
+    }
+}
+
+/// The state of every field registered with a form, in registration order.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FormState {
+    fields: Vec<(&'static str, FieldState)>,
+    submit_count: usize,
+}
+
+/// A snapshot of the values of every field registered with a form, e.g. to decide whether another field is
+/// required.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FormValues {
+    values: Vec<(&'static str, String)>,
+}
+
+impl FormValues {
+    /// Returns the value of the field with the given name, if it is registered.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(field_name, _)| *field_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns `true` when the field with the given name is registered and not blank.
+    pub fn is_filled(&self, name: &str) -> bool {
+        self.get(name).is_some_and(|value| !value.trim().is_empty())
+    }
+}
+
+/// Progress of a form through its required fields.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FormProgress {
+    /// The number of required fields that are valid and not blank.
+    pub completed: usize,
+    /// The number of required fields.
+    pub total: usize,
+}
+
+impl FormState {
+    /// Returns the state of the field with the given name, if it is registered.
+    pub fn field(&self, name: &str) -> Option<&FieldState> {
+        self.fields
+            .iter()
+            .find(|(field_name, _)| *field_name == name)
+            .map(|(_, field)| field)
+    }
+
+    /// Returns the registered fields, in registration order.
+    pub fn fields(&self) -> impl Iterator<Item = (&'static str, &FieldState)> {
+        self.fields.iter().map(|(name, field)| (*name, field))
+    }
+
+    /// values
+    /// Collects the current value of every registered field.
+    ///
+    /// # Examples
+    /// ```
+    /// use input_yew::form::{FieldState, FormAction, FormState};
+    /// use std::rc::Rc;
+    /// use yew::prelude::*;
+    ///
+    /// let form = Rc::new(FormState::default())
+    ///     .reduce(FormAction::Register("po_box", FieldState { value: "PO Box 42".into(), ..Default::default() }))
+    ///     .reduce(FormAction::Register("address_line_2", FieldState { value: " ".into(), ..Default::default() }));
+    ///
+    /// let values = form.values();
+    /// assert_eq!(values.get("po_box"), Some("PO Box 42"));
+    /// assert!(values.is_filled("po_box"));
+    /// assert!(!values.is_filled("address_line_2"));
+    /// assert!(!values.is_filled("city"));
+    /// ```
+    pub fn values(&self) -> FormValues {
+        FormValues {
+            values: self
+                .fields
+                .iter()
+                .map(|(name, field)| (*name, field.value.clone()))
+                .collect(),
+        }
+    }
+
+    /// Returns the number of fields with an error.
+    pub fn error_count(&self) -> usize {
+        self.fields.iter().filter(|(_, field)| field.has_error()).count()
+    }
+
+    /// Returns the name and state of the first field with an error, in registration order.
+    pub fn first_error(&self) -> Option<(&'static str, &FieldState)> {
+        self.fields().find(|(_, field)| field.has_error())
+    }
+
+    /// scroll_to_error
+    /// Scrolls the first field with an error into view and focuses it, e.g. after a failed submit of a
+    /// long form.
+    ///
+    /// # Returns
+    /// (bool): `true` if a field with an error was found and scrolled to.
+    pub fn scroll_to_error(&self) -> bool {
+        match self
+            .first_error()
+            .and_then(|(_, field)| field.input_ref.cast::<HtmlElement>())
+        {
+            Some(input) => {
+                input.scroll_into_view();
+                let _ = input.focus();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the number of times the form was submitted.
+    pub fn submit_count(&self) -> usize {
+        self.submit_count
+    }
+
+    /// progress
+    /// Counts the required fields and how many of them are completed.
+    ///
+    /// # Examples
+    /// ```
+    /// use input_yew::form::{FieldState, FormAction, FormProgress, FormState};
+    /// use std::rc::Rc;
+    /// use yew::prelude::*;
+    ///
+    /// let form = Rc::new(FormState::default())
+    ///     .reduce(FormAction::Register("name", FieldState { required: true, valid: true, value: "Ada".into(), ..Default::default() }))
+    ///     .reduce(FormAction::Register("email", FieldState { required: true, valid: false, value: "ada@".into(), ..Default::default() }))
+    ///     .reduce(FormAction::Register("phone", FieldState { required: true, valid: true, value: "".into(), ..Default::default() }))
+    ///     .reduce(FormAction::Register("nickname", FieldState { required: false, valid: true, value: "ada".into(), ..Default::default() }));
+    ///
+    /// assert_eq!(form.progress(), FormProgress { completed: 1, total: 3 });
+    /// ```
+    pub fn progress(&self) -> FormProgress {
+        let required = self.fields.iter().filter(|(_, field)| field.required);
+        FormProgress {
+            completed: required.clone().filter(|(_, field)| field.is_completed()).count(),
+            total: required.count(),
+        }
+    }
+}
+
+/// Actions dispatched by the fields of a form.
+pub enum FormAction {
+    /// Registers the field with the given name, or updates its state if already registered.
+    Register(&'static str, FieldState),
+    /// Removes the field with the given name from the form.
+    Unregister(&'static str),
+    /// Records a submission attempt, e.g. from the `onsubmit` handler of the form.
+    Submit,
+}
+
+impl Reducible for FormState {
+    type Action = FormAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut fields = self.fields.clone();
+        let mut submit_count = self.submit_count;
+        match action {
+            FormAction::Register(name, field) => {
+                match fields.iter_mut().find(|(field_name, _)| *field_name == name) {
+                    Some((_, existing)) if *existing == field => return self,
+                    Some((_, existing)) => *existing = field,
+                    None => fields.push((name, field)),
+                }
+            }
+            FormAction::Unregister(name) => {
+                let len = fields.len();
+                fields.retain(|(field_name, _)| *field_name != name);
+                if fields.len() == len {
+                    return self;
+                }
+            }
+            FormAction::Submit => submit_count += 1,
+        }
+        Rc::new(Self {
+            fields,
+            submit_count,
+        })
+    }
+}
+
+/// A handle to the state of a form, provided to its fields through `FormProvider`.
+pub type FormContext = UseReducerHandle<FormState>;
+
+/// use_form
+/// Creates the state of a form, to be passed to a `FormProvider` wrapping its fields.
+///
+/// # Returns
+/// (FormContext): A handle to the state of the form.
+#[hook]
+pub fn use_form() -> FormContext {
+    use_reducer(FormState::default)
+}
+
+/// use_form_progress
+/// Reads the progress of the enclosing form, e.g. to render "3 of 5 required fields completed".
+///
+/// # Returns
+/// (FormProgress): The completed and total required field counts, or zeros outside a `FormProvider`.
+#[hook]
+pub fn use_form_progress() -> FormProgress {
+    use_context::<FormContext>()
+        .map(|form| form.progress())
+        .unwrap_or_default()
+}
+
+/// Props for the form context provider.
+#[derive(Properties, PartialEq, Clone)]
+pub struct FormProviderProps {
+    /// The form state created with `use_form`.
+    pub form: FormContext,
+
+    /// The fields and other content of the form.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// form_provider
+/// Provides a form context to every `CustomInput` rendered inside it.
+///
+/// # Examples
+/// ```
+/// use input_yew::form::{use_form, use_form_progress, FormProvider};
+/// use input_yew::CustomInput;
+/// use yew::prelude::*;
+///
+/// #[function_component(Progress)]
+/// fn progress() -> Html {
+///     let progress = use_form_progress();
+///     html! {
+///         <p>{ format!("{} of {} required fields completed", progress.completed, progress.total) }</p>
+///     }
+/// }
+///
+/// #[function_component(SignUpForm)]
+/// fn sign_up_form() -> Html {
+///     let form = use_form();
+///     let name_ref = use_node_ref();
+///     let name_handle = use_state(String::default);
+///     let name_valid_handle = use_state(|| true);
+///
+///     html! {
+///         <FormProvider form={form}>
+///             <Progress />
+///             <CustomInput
+///               name={"name"}
+///               required={true}
+///               input_ref={name_ref}
+///               input_handle={name_handle}
+///               input_valid_handle={name_valid_handle}
+///               validate_function={|value: String| !value.is_empty()}
+///             />
+///         </FormProvider>
+///     }
+/// }
+/// ```
+#[function_component(FormProvider)]
+pub fn form_provider(props: &FormProviderProps) -> Html {
+    html! {
+        <ContextProvider<FormContext> context={props.form.clone()}>
+            { props.children.clone() }
+        </ContextProvider<FormContext>>
+    }
+}
+
+/// Props for the form status announcement.
+#[derive(Properties, PartialEq, Clone)]
+pub struct FormStatusProps {
+    /// The politeness of the announcement, "polite" or "assertive".
+    #[prop_or("polite")]
+    pub politeness: &'static str,
+
+    /// Whether the status is only exposed to screen readers.
+    #[prop_or(true)]
+    pub visually_hidden: bool,
+
+    /// The CSS class to be applied to the status element.
+    #[prop_or_default]
+    pub form_status_class: &'static str,
+
+    /// Whether the first field with an error is scrolled into view and focused after a failed submit.
+    #[prop_or_default]
+    pub scroll_to_error: bool,
+}
+
+/// form_status
+/// Announces the number of errors of the enclosing form to screen readers after a failed submit.
+///
+/// Submissions are recorded by dispatching `FormAction::Submit` on the form created with `use_form`.
+/// Each failed submit is announced, even when the number of errors is unchanged.
+#[function_component(FormStatus)]
+pub fn form_status(props: &FormStatusProps) -> Html {
+    let form = use_context::<FormContext>();
+    let (submit_count, error_count) = form
+        .as_ref()
+        .map(|form| (form.submit_count(), form.error_count()))
+        .unwrap_or_default();
+
+    {
+        let scroll_to_error = props.scroll_to_error;
+        use_effect_with(submit_count, move |submit_count| {
+            if let Some(form) = form.filter(|_| scroll_to_error && *submit_count > 0) {
+                form.scroll_to_error();
+            }
+        });
+    }
+
+    html! {
+        <div
+            class={props.form_status_class}
+            style={if props.visually_hidden { VISUALLY_HIDDEN_STYLE } else { "" }}
+            role="status"
+            aria-live={props.politeness}
+            aria-atomic="true"
+        >
+            if submit_count > 0 && error_count > 0 {
+                <span key={submit_count}>
+                    { format!("Form has {} error{}", error_count, if error_count == 1 { "" } else { "s" }) }
+                </span>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// The validity of every member of a group of inputs, keyed by the input `name`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ValidityGroupState {
+    members: HashMap<&'static str, bool>,
+}
+
+impl ValidityGroupState {
+    /// Returns `true` when every member of the group is valid.
+    pub fn is_valid(&self) -> bool {
+        self.members.values().all(|valid| *valid)
+    }
+}
+
+/// Actions dispatched by the members of a validity group.
+pub enum ValidityGroupAction {
+    /// Records the validity of the member with the given name.
+    Set(&'static str, bool),
+    /// Removes the member with the given name from the group.
+    Remove(&'static str),
+}
+
+impl Reducible for ValidityGroupState {
+    type Action = ValidityGroupAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut members = self.members.clone();
+        match action {
+            ValidityGroupAction::Set(name, valid) => {
+                if self.members.get(name) == Some(&valid) {
+                    return self;
+                }
+                members.insert(name, valid);
+            }
+            ValidityGroupAction::Remove(name) => {
+                if members.remove(name).is_none() {
+                    return self;
+                }
+            }
+        }
+        Rc::new(Self { members })
+    }
+}
+
+/// A handle shared by several `CustomInput`s through their `group_valid_handle` prop.
+pub type ValidityGroup = UseReducerHandle<ValidityGroupState>;
+
+/// use_validity_group
+/// Creates a validity group that ANDs the validity of every `CustomInput` it is passed to.
+///
+/// Each member reports its own validity under its `name`, so members of a group must have distinct
+/// names. A member leaves the group when it is unmounted.
+///
+/// # Returns
+/// (ValidityGroup): A handle to pass as `group_valid_handle` to each member of the group.
+///
+/// # Examples
+/// ```
+/// use input_yew::{use_validity_group, CustomInput};
+/// use yew::prelude::*;
+///
+/// #[function_component(AddressForm)]
+/// pub fn address_form() -> Html {
+///     let address_valid = use_validity_group();
+///
+///     let street_ref = use_node_ref();
+///     let street_handle = use_state(String::default);
+///     let street_valid_handle = use_state(|| true);
+///
+///     let city_ref = use_node_ref();
+///     let city_handle = use_state(String::default);
+///     let city_valid_handle = use_state(|| true);
+///
+///     let not_blank = Callback::from(|value: String| !value.trim().is_empty());
+///
+///     html! {
+///         <fieldset>
+///             <CustomInput
+///               name={"street"}
+///               input_ref={street_ref}
+///               input_handle={street_handle}
+///               input_valid_handle={street_valid_handle}
+///               validate_function={not_blank.clone()}
+///               group_valid_handle={address_valid.clone()}
+///             />
+///             <CustomInput
+///               name={"city"}
+///               input_ref={city_ref}
+///               input_handle={city_handle}
+///               input_valid_handle={city_valid_handle}
+///               validate_function={not_blank}
+///               group_valid_handle={address_valid.clone()}
+///             />
+///             if !address_valid.is_valid() {
+///               <p>{"Please complete the address."}</p>
+///             }
+///         </fieldset>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_validity_group() -> ValidityGroup {
+    use_reducer(ValidityGroupState::default)
+}
+
+/// Props for the input group.
+#[derive(Properties, PartialEq, Clone)]
+pub struct InputGroupProps {
+    /// The name of the group, used to derive the ID of its error region.
+    pub name: &'static str,
+
+    /// The legend labelling every input of the group, e.g. "Date of birth".
+    pub legend: &'static str,
+
+    /// The validity group passed as `group_valid_handle` to each input of the group.
+    pub group: ValidityGroup,
+
+    /// The error message displayed once for the whole group while any of its inputs is invalid.
+    #[prop_or_default]
+    pub error_message: &'static str,
+
+    /// The inputs of the group.
+    #[prop_or_default]
+    pub children: Html,
+
+    /// The CSS class to be applied to the container.
+    #[prop_or_default]
+    pub form_input_class: &'static str,
+
+    /// The CSS class to be applied to the legend.
+    #[prop_or_default]
+    pub form_input_label_class: &'static str,
+
+    /// The CSS class to be applied to the element wrapping the inputs.
+    #[prop_or_default]
+    pub form_input_field_class: &'static str,
+
+    /// The CSS class to be applied to the shared error message.
+    #[prop_or_default]
+    pub form_input_error_class: &'static str,
+}
+
+/// custom_input_group
+/// Groups compound inputs, such as a date split into day, month and year, under one legend and one
+/// error region aggregating their validity.
+///
+/// Each input of the group reports to the same validity group. To display the shared error only, the
+/// inputs set `show_error_text` to `false` and point `aria_describedby` to the error region, whose ID is
+/// the group `name` followed by `-error`.
+///
+/// # Examples
+/// ```
+/// use input_yew::{use_validity_group, CustomInput, CustomInputGroup};
+/// use yew::prelude::*;
+///
+/// #[function_component(BirthDate)]
+/// pub fn birth_date() -> Html {
+///     let birth_date_valid = use_validity_group();
+///
+///     let day_ref = use_node_ref();
+///     let day_handle = use_state(String::default);
+///     let day_valid_handle = use_state(|| true);
+///
+///     let month_ref = use_node_ref();
+///     let month_handle = use_state(String::default);
+///     let month_valid_handle = use_state(|| true);
+///
+///     let in_range = |max: u32| {
+///         Callback::from(move |value: String| value.parse::<u32>().is_ok_and(|value| (1..=max).contains(&value)))
+///     };
+///
+///     html! {
+///         <CustomInputGroup
+///           name={"birth-date"}
+///           legend={"Date of birth"}
+///           group={birth_date_valid.clone()}
+///           error_message={"Please enter a valid date."}
+///         >
+///             <CustomInput
+///               name={"day"}
+///               aria_label={"Day"}
+///               input_ref={day_ref}
+///               input_handle={day_handle}
+///               input_valid_handle={day_valid_handle}
+///               validate_function={in_range(31)}
+///               group_valid_handle={birth_date_valid.clone()}
+///               show_error_text={false}
+///               aria_describedby={"birth-date-error"}
+///             />
+///             <CustomInput
+///               name={"month"}
+///               aria_label={"Month"}
+///               input_ref={month_ref}
+///               input_handle={month_handle}
+///               input_valid_handle={month_valid_handle}
+///               validate_function={in_range(12)}
+///               group_valid_handle={birth_date_valid}
+///               show_error_text={false}
+///               aria_describedby={"birth-date-error"}
+///             />
+///         </CustomInputGroup>
+///     }
+/// }
+/// ```
+#[function_component(CustomInputGroup)]
+pub fn custom_input_group(props: &InputGroupProps) -> Html {
+    let error_id = format!("{}-error", props.name);
+    let valid = props.group.is_valid();
+
+    html! {
+        <fieldset class={props.form_input_class} role="group">
+            <legend class={props.form_input_label_class}>{ props.legend }</legend>
+            <div class={props.form_input_field_class}>
+                { props.children.clone() }
+            </div>
+            if !valid && !props.error_message.is_empty() {
+                <div class={props.form_input_error_class} id={error_id} role="alert">
+                    { props.error_message }
+                </div>
+            }
+        </fieldset>
+    }
+}
@@ -0,0 +1,308 @@
+use crate::files::{files_meta, FileMeta};
+use crate::text::{truncate_decimals, truncate_graphemes};
+use crate::validation::{validate_value, ValidationOptions, ValidationState, ValidationTrigger};
+use unicode_segmentation::UnicodeSegmentation;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlInputElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+/// The event handlers of a field, to attach to an input or textarea rendered with custom markup.
+#[derive(Clone, PartialEq)]
+pub struct InputHandlers {
+    /// The `oninput` handler, updating the value on every keystroke.
+    pub oninput: Callback<InputEvent>,
+    /// The `onchange` handler, updating the value instead of `oninput` when `validate_on` is
+    /// `ValidationTrigger::Change`.
+    pub onchange: Callback<Event>,
+    /// The `onblur` handler, validating the value once the user leaves the field when `validate_on` is
+    /// `ValidationTrigger::Blur` or `ValidationTrigger::Both`.
+    pub onblur: Callback<FocusEvent>,
+}
+
+/// How a field processes and tracks the values typed into it, mirroring the props of `CustomInput`.
+#[derive(Clone, PartialEq)]
+pub struct ValueHandling {
+    /// Parses the displayed value back into the stored one, like the `parse` prop.
+    pub parse: Option<Callback<String, String>>,
+    /// Formats the stored value for display, like the `display_format` prop.
+    pub display_format: Option<Callback<String, String>>,
+    /// Normalizes the parsed value, like the `value_middleware` prop.
+    pub value_middleware: Vec<Callback<String, String>>,
+    /// The maximum number of decimal places, like the `max_decimals` prop.
+    pub max_decimals: Option<usize>,
+    /// The decimal separator used by `max_decimals`, like the `decimal_separator` prop.
+    pub decimal_separator: char,
+    /// The maximum number of graphemes, enforced while typing since the native `maxlength` counts UTF-16
+    /// code units.
+    pub grapheme_limit: Option<usize>,
+    /// Receives the metadata of the selected files of a file input, like the `on_files_meta` prop.
+    pub on_files_meta: Callback<Vec<FileMeta>>,
+    /// The state handle set to `true` once the user edits the value.
+    pub touched_handle: Option<UseStateHandle<bool>>,
+}
+
+impl Default for ValueHandling {
+    fn default() -> Self {
+        Self {
+            parse: None,
+            display_format: None,
+            value_middleware: Vec::new(),
+            max_decimals: None,
+            decimal_separator: '.',
+            grapheme_limit: None,
+            on_files_meta: Callback::noop(),
+            touched_handle: None,
+        }
+    }
+}
+
+/// The editable elements a field can be rendered as.
+enum Field {
+    Input(HtmlInputElement),
+    TextArea(HtmlTextAreaElement),
+}
+
+impl Field {
+    fn new(element: &Element) -> Option<Self> {
+        if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+            Some(Self::Input(input.clone()))
+        } else {
+            element
+                .dyn_ref::<HtmlTextAreaElement>()
+                .map(|textarea| Self::TextArea(textarea.clone()))
+        }
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::Input(input) => input.value(),
+            Self::TextArea(textarea) => textarea.value(),
+        }
+    }
+
+    fn set_value(&self, value: &str) {
+        match self {
+            Self::Input(input) => input.set_value(value),
+            Self::TextArea(textarea) => textarea.set_value(value),
+        }
+    }
+
+    fn selection_start(&self) -> Option<u32> {
+        match self {
+            Self::Input(input) => input.selection_start().ok().flatten(),
+            Self::TextArea(textarea) => textarea.selection_start().ok().flatten(),
+        }
+    }
+
+    fn set_caret(&self, position: u32) {
+        let _ = match self {
+            Self::Input(input) => input.set_selection_range(position, position),
+            Self::TextArea(textarea) => textarea.set_selection_range(position, position),
+        };
+    }
+}
+
+/// Returns the prefix of `value` spanning its first `units` UTF-16 code units, as used by DOM selections.
+fn utf16_prefix(value: &str, units: usize) -> &str {
+    let mut count = 0;
+    for (index, c) in value.char_indices() {
+        if count >= units {
+            return &value[..index];
+        }
+        count += c.len_utf16();
+    }
+    value
+}
+
+/// Reads the value of the edited input or textarea `element`, processes it as set by `handling`, rewriting
+/// the element when the displayed value changes, then stores it in `input_handle`. The value is validated
+/// into `input_valid_handle` when `validate` is set. This is the change logic shared by `CustomInput` and
+/// `make_input_handlers`.
+pub(crate) fn update_field(
+    element: &Element,
+    handling: &ValueHandling,
+    input_handle: &UseStateHandle<String>,
+    input_valid_handle: &UseStateHandle<bool>,
+    validate: Option<&Callback<String, ValidationState>>,
+) {
+    let Some(field) = Field::new(element) else {
+        return;
+    };
+    if let Field::Input(input) = &field {
+        // Only file inputs have a file list
+        if let Some(files) = input.files() {
+            handling.on_files_meta.emit(files_meta(&files));
+        }
+    }
+    let display_format = &handling.display_format;
+    let displayed = field.value();
+    let parse_value = |value: &str| match &handling.parse {
+        Some(parse) => parse.emit(value.to_string()),
+        None => value.to_string(),
+    };
+    let mut value = parse_value(&displayed);
+    let normalized = handling
+        .value_middleware
+        .iter()
+        .fold(value.clone(), |value, middleware| middleware.emit(value));
+    if normalized != value {
+        value = normalized;
+        if display_format.is_none() {
+            field.set_value(&value);
+        }
+    }
+    if let Some(max_decimals) = handling.max_decimals {
+        let truncated = truncate_decimals(&value, max_decimals, handling.decimal_separator);
+        if truncated.len() < value.len() {
+            value = truncated.to_string();
+            if display_format.is_none() {
+                field.set_value(&value);
+            }
+        }
+    }
+    if let Some(max_length) = handling.grapheme_limit {
+        if value.graphemes(true).count() > max_length {
+            value = truncate_graphemes(&value, max_length).to_string();
+            if display_format.is_none() {
+                field.set_value(&value);
+            }
+        }
+    }
+    if let Some(display_format) = display_format {
+        let formatted = display_format.emit(value.clone());
+        if formatted != displayed {
+            // Keep the caret after the same raw characters it followed before formatting.
+            let caret = field
+                .selection_start()
+                .map(|caret| utf16_prefix(&displayed, caret as usize))
+                .map(|prefix| parse_value(prefix).chars().count());
+            field.set_value(&formatted);
+            if let Some(raw_before) = caret {
+                let position = formatted
+                    .char_indices()
+                    .map(|(index, _)| index)
+                    .chain(std::iter::once(formatted.len()))
+                    .find(|index| parse_value(&formatted[..*index]).chars().count() >= raw_before)
+                    .unwrap_or(formatted.len());
+                field.set_caret(formatted[..position].encode_utf16().count() as u32);
+            }
+        }
+    }
+    input_handle.set(value.clone());
+    if let Some(validate) = validate {
+        input_valid_handle.set(validate.emit(value).is_valid());
+    }
+    if let Some(touched_handle) = &handling.touched_handle {
+        touched_handle.set(true);
+    }
+}
+
+/// make_input_handlers
+/// Builds the change and validation handlers of `CustomInput` for an element rendered with custom markup.
+///
+/// Unlike the `render` prop, which hands over the handlers of a mounted `CustomInput`, these only depend on
+/// the state handles, so they can be attached to any input or textarea. The value is read from the event
+/// target and processed as set by `handling`, exactly as `CustomInput` does, then validated with
+/// `validate_value`, running the built-in checks of `options` before `validate_function`.
+///
+/// # Arguments
+/// * `input_handle` - The state handle updated with the value.
+/// * `input_valid_handle` - The state handle updated with the validity of the value.
+/// * `validate_function` - A custom validator returning `false` for invalid values.
+/// * `options` - The built-in checks to run.
+/// * `validate_on` - The event updating and validating the value.
+/// * `handling` - How the value is processed and tracked, e.g. parsed or limited in decimals.
+///
+/// # Returns
+/// (InputHandlers): The handlers to attach to the element.
+///
+/// # Examples
+/// ```
+/// use input_yew::handlers::ValueHandling;
+/// use input_yew::make_input_handlers;
+/// use input_yew::validation::{ValidationOptions, ValidationTrigger};
+/// use yew::prelude::*;
+///
+/// #[function_component(Nickname)]
+/// fn nickname() -> Html {
+///     let nickname_handle = use_state(String::default);
+///     let nickname_valid_handle = use_state(|| true);
+///     let nickname_touched_handle = use_state(|| false);
+///     let handlers = make_input_handlers(
+///         nickname_handle.clone(),
+///         nickname_valid_handle.clone(),
+///         Callback::from(|nickname: String| nickname.len() <= 16),
+///         ValidationOptions { required: true, ..Default::default() },
+///         ValidationTrigger::Input,
+///         ValueHandling {
+///             value_middleware: vec![Callback::from(|nickname: String| nickname.to_lowercase())],
+///             touched_handle: Some(nickname_touched_handle.clone()),
+///             ..Default::default()
+///         },
+///     );
+///
+///     html! {
+///         <input
+///             class={if *nickname_valid_handle { "nickname" } else { "nickname invalid" }}
+///             value={(*nickname_handle).clone()}
+///             oninput={handlers.oninput}
+///             onchange={handlers.onchange}
+///             onblur={handlers.onblur}
+///         />
+///     }
+/// }
+/// ```
+pub fn make_input_handlers(
+    input_handle: UseStateHandle<String>,
+    input_valid_handle: UseStateHandle<bool>,
+    validate_function: Callback<String, bool>,
+    options: ValidationOptions,
+    validate_on: ValidationTrigger,
+    handling: ValueHandling,
+) -> InputHandlers {
+    let validate =
+        Callback::from(move |value: String| validate_value(&value, &validate_function, &options));
+
+    let update_value = {
+        let input_handle = input_handle.clone();
+        let input_valid_handle = input_valid_handle.clone();
+        let validate = validate.clone();
+        // With `ValidationTrigger::Blur`, edits are only validated once the user leaves the field
+        let validate_now = validate_on != ValidationTrigger::Blur;
+        Callback::from(move |event: Event| {
+            if let Some(element) = event.target_dyn_into::<Element>() {
+                let validate = validate_now.then_some(&validate);
+                update_field(
+                    &element,
+                    &handling,
+                    &input_handle,
+                    &input_valid_handle,
+                    validate,
+                );
+            }
+        })
+    };
+
+    let (oninput, onchange) = match validate_on {
+        ValidationTrigger::Input | ValidationTrigger::Blur | ValidationTrigger::Both => (
+            update_value.reform(|event: InputEvent| event.into()),
+            Callback::noop(),
+        ),
+        ValidationTrigger::Change => (Callback::noop(), update_value),
+    };
+
+    let onblur = match validate_on {
+        ValidationTrigger::Blur | ValidationTrigger::Both => {
+            Callback::from(move |_: FocusEvent| {
+                input_valid_handle.set(validate.emit((*input_handle).clone()).is_valid());
+            })
+        }
+        ValidationTrigger::Input | ValidationTrigger::Change => Callback::noop(),
+    };
+
+    InputHandlers {
+        oninput,
+        onchange,
+        onblur,
+    }
+}
@@ -0,0 +1,49 @@
+/// The maximum number of values kept to undo.
+const UNDO_LIMIT: usize = 100;
+
+/// An undo/redo history of the values of an input, used instead of the native undo stack which breaks
+/// when the value is rewritten programmatically.
+#[derive(Debug, Default)]
+pub(crate) struct UndoHistory {
+    past: Vec<String>,
+    future: Vec<String>,
+    current: Option<String>,
+    restoring: bool,
+}
+
+impl UndoHistory {
+    /// Records a new value of the input, unless it comes from an undo or redo.
+    pub(crate) fn record(&mut self, value: &str) {
+        if self.restoring {
+            self.restoring = false;
+            self.current = Some(value.to_string());
+            return;
+        }
+        match self.current.replace(value.to_string()) {
+            Some(previous) if previous != value => {
+                self.past.push(previous);
+                if self.past.len() > UNDO_LIMIT {
+                    self.past.remove(0);
+                }
+                self.future.clear();
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the previous value, if any, moving the current one to the redo stack.
+    pub(crate) fn undo(&mut self) -> Option<String> {
+        let previous = self.past.pop()?;
+        self.future.extend(self.current.replace(previous.clone()));
+        self.restoring = true;
+        Some(previous)
+    }
+
+    /// Returns the next value, if any, moving the current one to the undo stack.
+    pub(crate) fn redo(&mut self) -> Option<String> {
+        let next = self.future.pop()?;
+        self.past.extend(self.current.replace(next.clone()));
+        self.restoring = true;
+        Some(next)
+    }
+}
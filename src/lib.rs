@@ -1,7 +1,59 @@
+pub mod autocomplete;
+pub mod combobox;
+pub mod confirm;
+pub mod count;
 pub mod countries;
+pub mod debounce;
+pub mod files;
+pub mod form;
+pub mod group;
+pub mod handlers;
+mod history;
+pub mod phone;
+pub mod segmented;
+pub mod strength;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod text;
+pub mod validation;
+pub mod validators;
 
-use crate::countries::COUNTRY_CODES;
-use web_sys::HtmlInputElement;
+use crate::countries::{Country, CountrySort};
+#[cfg(feature = "phone")]
+use crate::countries::{search_countries_in, sort_countries, COUNTRY_CODES};
+use crate::files::FileMeta;
+use crate::form::{FieldState, FormAction, FormContext, FormValues};
+use crate::group::ValidityGroupAction;
+use crate::history::UndoHistory;
+#[cfg(feature = "phone")]
+use crate::phone::{
+    display_length, display_phone, national_length, parse_phone_number_in, E164_MAX_DIGITS,
+};
+use crate::phone::PhoneValue;
+use crate::strength::{password_strength, MAX_STRENGTH};
+use crate::text::mask_value;
+use crate::validation::{
+    text_length, validate_value, word_count, ValidationError, ValidationOptions, ValidationState,
+    ValidationTrigger,
+};
+pub use crate::combobox::{ComboboxProps, CustomCombobox};
+pub use crate::confirm::{ConfirmInputProps, CustomConfirmInput};
+pub use crate::count::{CountUpProps, CustomCountUp};
+pub use crate::debounce::use_debounce;
+pub use crate::group::{
+    use_validity_group, CustomInputGroup, InputGroupProps, ValidityGroup, ValidityGroupState,
+};
+pub use crate::handlers::{make_input_handlers, InputHandlers};
+use crate::handlers::{update_field, ValueHandling};
+pub use crate::segmented::{CustomSegmented, SegmentedProps};
+use gloo_timers::callback::Interval;
+#[cfg(feature = "phone")]
+use std::collections::HashSet;
+#[cfg(feature = "phone")]
+use wasm_bindgen::JsCast;
+#[cfg(feature = "phone")]
+use web_sys::{ClipboardEvent, HtmlSelectElement};
+use web_sys::{Element, HtmlElement, HtmlInputElement, HtmlTextAreaElement};
 use yew::prelude::*;
 
 /// Props for a custom input component.
@@ -15,7 +67,13 @@ pub struct Props {
     #[prop_or_default]
     pub label: &'static str,
 
-    /// The name of the input field, used for form submission and accessibility.
+    /// Whether the label is rendered after the input rather than before it. When `None`, it is only
+    /// rendered after checkboxes and radio buttons.
+    #[prop_or_default]
+    pub label_after_input: Option<bool>,
+
+    /// The name of the input field, used for form submission and accessibility. Changing it resets the
+    /// internal state of the field, so an instance reused for another field doesn't keep stale errors.
     #[prop_or_default]
     pub name: &'static str,
 
@@ -23,6 +81,16 @@ pub struct Props {
     #[prop_or_default]
     pub required: bool,
 
+    /// Computes whether the field is currently required from the values of the enclosing form, e.g. to require
+    /// a second address line only when a PO box is given. Overrides `required` inside a `FormProvider`.
+    #[prop_or_default]
+    pub required_when: Option<Callback<FormValues, bool>>,
+
+    /// Whether a blank value is valid without running `validate_function` or `min_strength`, e.g. for an
+    /// optional email that must only be valid if given. Defaults to `true` unless the field is required.
+    #[prop_or_default]
+    pub allow_empty: Option<bool>,
+
     /// A reference to the DOM node of the input element.
     pub input_ref: NodeRef,
 
@@ -34,10 +102,62 @@ pub struct Props {
     #[prop_or_default]
     pub form_input_class: &'static str,
 
+    /// The CSS class added to the container while the input has focus, e.g. to highlight the whole field.
+    /// The password toggle and clear buttons are focusable, so they can be styled with `:focus-visible`.
+    #[prop_or_default]
+    pub focus_class: &'static str,
+
+    /// Inline styles applied to the container element, e.g. to set CSS custom properties. Accepts styles
+    /// computed at runtime as well as static strings.
+    #[prop_or_default]
+    pub style: AttrValue,
+
+    /// Inline styles applied to the input element, e.g. a width computed at runtime.
+    #[prop_or_default]
+    pub input_style: AttrValue,
+
+    /// Whether the field is hidden while staying mounted, e.g. during an animation. A hidden field is
+    /// removed from the accessibility tree and the tab order, and its container gets `form_input_hidden_class`.
+    #[prop_or_default]
+    pub hidden: bool,
+
+    /// Whether the input and its buttons are disabled.
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// Whether a disabled field also gets the `inert` attribute on its container, removing the whole field,
+    /// including custom content such as a `suffix`, from interaction and the accessibility tree. Browsers
+    /// without `inert` support still get the `disabled` attribute of each element.
+    #[prop_or_default]
+    pub inert: bool,
+
+    /// The tabindex of the input, e.g. to follow a visual order differing from the DOM order, or `-1` for
+    /// fields that are only focused programmatically. Ignored while `hidden` is set.
+    #[prop_or_default]
+    pub tabindex: Option<i32>,
+
+    /// The CSS class added to the container while `hidden` is set, e.g. to apply `display: none`.
+    #[prop_or_default]
+    pub form_input_hidden_class: &'static str,
+
+    /// The error color, exposed to styles as the `--input-error-color` custom property on the container.
+    #[prop_or_default]
+    pub error_color: &'static str,
+
+    /// The success color, exposed to styles as the `--input-success-color` custom property on the container.
+    #[prop_or_default]
+    pub success_color: &'static str,
+
     /// The CSS class to be applied to the inner input element and icon.
     #[prop_or_default]
     pub form_input_field_class: &'static str,
 
+    /// Whether the input and its icons are wrapped in a `form_input_field_class` element. When `false`, they
+    /// are rendered directly in the container, e.g. to lay them out as items of a CSS grid, which should then
+    /// be positioned for the icons and the country list of tel inputs to be placed against it.
+    #[prop_or(true)]
+    pub wrap_field: bool,
+
     /// The CSS class to be applied to the label for the input element.
     #[prop_or_default]
     pub form_input_label_class: &'static str,
@@ -50,19 +170,364 @@ pub struct Props {
     #[prop_or_default]
     pub form_input_error_class: &'static str,
 
+    /// The CSS class to be applied to the output element.
+    #[prop_or_default]
+    pub form_input_output_class: &'static str,
+
+    /// The CSS class to be applied to the empty state element, typically absolutely positioned over the input.
+    #[prop_or_default]
+    pub form_input_empty_state_class: &'static str,
+
+    /// The CSS class to be applied to the element wrapping the prefix.
+    #[prop_or_default]
+    pub form_input_prefix_class: &'static str,
+
+    /// The CSS class to be applied to the element wrapping the suffix.
+    #[prop_or_default]
+    pub form_input_suffix_class: &'static str,
+
+    /// The CSS class to be applied to the character counter element.
+    #[prop_or_default]
+    pub form_input_counter_class: &'static str,
+
+    /// The CSS class added to the character counter once `counter_warn_threshold` is reached.
+    #[prop_or("warning")]
+    pub form_input_counter_warn_class: &'static str,
+
+    /// The CSS class added to the character counter once `counter_danger_threshold` is reached.
+    #[prop_or("danger")]
+    pub form_input_counter_danger_class: &'static str,
+
+    /// The CSS class to be applied to the strength meter element.
+    #[prop_or_default]
+    pub form_input_strength_class: &'static str,
+
+    /// The CSS class to be applied to the list of strength stages. The reached stages get a `reached` class.
+    #[prop_or_default]
+    pub form_input_strength_labels_class: &'static str,
+
+    /// The CSS class to be applied to the range meter of number inputs.
+    #[prop_or_default]
+    pub form_input_meter_class: &'static str,
+
+    /// The CSS class to be applied to the static text element in plaintext mode.
+    #[prop_or_default]
+    pub form_input_plaintext_class: &'static str,
+
     /// The CSS class to be applied to the icon element.
     #[prop_or_default]
     pub icon_class: &'static str,
 
+    /// The CSS class to be applied to the inline validation icon element.
+    #[prop_or_default]
+    pub validation_icon_class: &'static str,
+
+    /// Optional content rendered inside an `<output>` element linked to the input, e.g. a computed total.
+    #[prop_or_default]
+    pub output: Option<Html>,
+
+    /// The maximum number of characters allowed, emitted as the `maxlength` attribute.
+    #[prop_or_default]
+    pub max_length: Option<usize>,
+
+    /// Whether `max_length` is emitted as the `maxlength` attribute, letting the browser block extra characters.
+    /// When `false`, longer values can be typed but are flagged as invalid; combined with `show_counter`, the
+    /// counter then shows by how much the limit is exceeded.
+    #[prop_or(true)]
+    pub enforce_max_length: bool,
+
+    /// Whether the counter and `max_length` count grapheme clusters instead of characters, so that an emoji
+    /// like 👨‍👩‍👧 counts as one character, as users perceive it.
+    #[prop_or_default]
+    pub count_graphemes: bool,
+
+    /// Whether to display a character counter when `max_length` is set.
+    #[prop_or_default]
+    pub show_counter: bool,
+
+    /// The counter style: "text" renders `count/max`, "ring" renders a circular progress indicator.
+    #[prop_or("text")]
+    pub counter_style: &'static str,
+
+    /// The fraction of `max_length` from which the counter gets `form_input_counter_warn_class`, e.g. `0.8`.
+    #[prop_or_default]
+    pub counter_warn_threshold: Option<f64>,
+
+    /// The fraction of `max_length` from which the counter gets `form_input_counter_danger_class`, e.g. `1.0`.
+    /// It replaces the warning class.
+    #[prop_or_default]
+    pub counter_danger_threshold: Option<f64>,
+
+    /// Validates the value every given number of milliseconds while the field is focused and has been edited,
+    /// giving incremental feedback on long entries. `0` disables periodic validation.
+    #[prop_or_default]
+    pub validate_interval_ms: u32,
+
+    /// Whether Ctrl+Z/Ctrl+Y (Cmd on macOS) undo and redo through an internal history of values, which keeps
+    /// working when the value is rewritten programmatically, e.g. by the tel input's digit filtering.
+    #[prop_or_default]
+    pub enable_undo: bool,
+
+    /// Whether to render the value as static text instead of an input, preserving the label and layout.
+    #[prop_or_default]
+    pub plaintext: bool,
+
+    /// The number of trailing characters left visible when displaying the value in `plaintext` mode, the
+    /// others being masked, e.g. `Some(4)` for "•••• •••• •••• 1234". The full value is still submitted
+    /// unless `mask_display_only` is set.
+    #[prop_or_default]
+    pub mask_display: Option<usize>,
+
+    /// Whether the hidden input of `plaintext` mode is left out when `mask_display` is set, so that the
+    /// full value is only displayed masked and never submitted with the form.
+    #[prop_or_default]
+    pub mask_display_only: bool,
+
+    /// Formats the stored value for display, e.g. grouping card digits as "4111 1111 1111 1111".
+    /// Only the rendered value is formatted, `input_handle` keeps the raw value.
+    #[prop_or_default]
+    pub display_format: Option<Callback<String, String>>,
+
+    /// Parses the displayed value back into the raw value stored in `input_handle`, e.g. removing the
+    /// spaces added by `display_format`. Defaults to keeping the displayed value as is.
+    #[prop_or_default]
+    pub parse: Option<Callback<String, String>>,
+
+    /// Normalization steps applied in order to the typed value, after `parse` and before the value is
+    /// limited, validated and stored, e.g. trimming then uppercasing. When a step changes the value, the
+    /// input is rewritten and the caret moves to the end, so steps should leave partial input as typed.
+    #[prop_or_default]
+    pub value_middleware: Vec<Callback<String, String>>,
+
+    /// Whether runs of whitespace are collapsed into a single space and the value trimmed when the input
+    /// loses focus, e.g. for names.
+    #[prop_or_default]
+    pub collapse_whitespace: bool,
+
+    /// Fires `on_complete` once the value reaches this length, e.g. to auto-submit a PIN or postal code.
+    #[prop_or_default]
+    pub auto_complete_at_length: Option<usize>,
+
+    /// A callback receiving the value when it reaches `auto_complete_at_length`.
+    #[prop_or_default]
+    pub on_complete: Callback<String>,
+
+    /// A callback receiving the `name`, value and validity of the field whenever the user changes it, so
+    /// that a single handler can serve many fields.
+    #[prop_or_default]
+    pub on_named_change: Callback<(&'static str, String, bool)>,
+
+    /// The event updating and validating the value, e.g. `ValidationTrigger::Change` to only update it once
+    /// committed when live updates would cause expensive re-renders, or `ValidationTrigger::Blur` to only
+    /// display the error once the user leaves the field.
+    #[prop_or_default]
+    pub validate_on: ValidationTrigger,
+
+    /// The delay, in milliseconds, the user must stop typing for before the value is validated, e.g. for an
+    /// expensive `validate_function`. `input_handle` is still updated on every keystroke, while
+    /// `input_valid_handle` lags behind. `0` validates immediately.
+    #[prop_or_default]
+    pub debounce_ms: u32,
+
+    /// A callback receiving the unmodified native `change` event of the input, e.g. to read `isTrusted`
+    /// for analytics. It fires alongside the internal handling, whatever `validate_on` is.
+    #[prop_or_default]
+    pub on_raw_change: Callback<Event>,
+
+    /// A callback receiving the parsed phone number whenever the number or country of a tel input changes.
+    #[prop_or_default]
+    pub on_phone_change: Callback<PhoneValue>,
+
+    /// A callback receiving the name, size, MIME type and modification time of the selected files of a
+    /// file input whenever the selection changes.
+    #[prop_or_default]
+    pub on_files_meta: Callback<Vec<FileMeta>>,
+
+    /// Whether the country `<select>` of tel inputs is replaced by a combobox searching countries by name
+    /// or dialing code, navigable with the arrow keys. The list is virtualized, so its options must stay
+    /// 32px high.
+    #[prop_or_default]
+    pub searchable_countries: bool,
+
+    /// Returns the URL of the flag image of a country, e.g. an SVG from a sprite or CDN, shown instead of the
+    /// emoji flag in the searchable country list. Images are lazy-loaded and get a `loading` class until
+    /// they are loaded. The native `<select>` keeps the emoji flags.
+    #[prop_or_default]
+    pub flag_src: Option<Callback<Country, AttrValue>>,
+
+    /// Whether the options of the native country `<select>` are only rendered once it is focused or clicked,
+    /// showing the selected country until then. This keeps the initial DOM small on pages with many tel
+    /// fields. The searchable country list is always built on demand.
+    #[prop_or_default]
+    pub defer_country_list: bool,
+
+    /// The order of the countries in the country selector, e.g. `CountrySort::ByDialCode` or a curated
+    /// `CountrySort::Custom` sequence. Defaults to the order of the country list.
+    #[prop_or_default]
+    pub country_sort: CountrySort,
+
+    /// A stored E.164 phone number, e.g. "+442079460958", used on mount to select its country and display
+    /// it in the national format of that country. Like user edits, it is stored in `input_handle` in E.164.
+    #[prop_or_default]
+    pub initial_phone: &'static str,
+
+    /// Whether the `+` of the dialing code is kept in the stored tel value. When unset, `input_handle` only
+    /// holds the digits, while the field still displays the `+`.
+    #[prop_or(true)]
+    pub tel_include_plus: bool,
+
+    /// A custom list of countries replacing the built-in `COUNTRY_CODES` in the tel input, e.g. to only offer
+    /// the operating regions of a business or to add special dialing codes.
+    #[prop_or_default]
+    pub countries: Option<&'static [Country]>,
+
+    /// The maximum number of decimal places, extra digits being dropped while typing, e.g. `Some(2)` for a
+    /// price field.
+    #[prop_or_default]
+    pub max_decimals: Option<usize>,
+
+    /// The decimal separator of the locale, used by `max_decimals`.
+    #[prop_or('.')]
+    pub decimal_separator: char,
+
+    /// The minimum value allowed for number and range inputs. Lower values mark the input invalid.
+    #[prop_or_default]
+    pub min: Option<f64>,
+
+    /// The maximum value allowed for number and range inputs. Greater values mark the input invalid.
+    #[prop_or_default]
+    pub max: Option<f64>,
+
+    /// The step used when incrementing number and range inputs with the arrow keys. Defaults to `1` when
+    /// unset.
+    #[prop_or_default]
+    pub step: Option<f64>,
+
+    /// The error message displayed when a number input is not a whole number of `step`s away from `min`.
+    /// Falls back to `error_message` when empty.
+    #[prop_or_default]
+    pub step_mismatch_message: &'static str,
+
+    /// Whether the messages of every failed check are displayed as a list instead of a single message.
+    /// The custom validator failing uses `error_message`, other checks use their default message.
+    #[prop_or_default]
+    pub show_all_errors: bool,
+
+    /// Whether scrolling the mouse wheel over a focused number input is prevented from changing its value.
+    #[prop_or(true)]
+    pub disable_wheel: bool,
+
+    /// Whether a `<meter>` showing where the value sits between `min` and `max` is rendered below number
+    /// inputs. Requires both `min` and `max`.
+    #[prop_or_default]
+    pub show_meter: bool,
+
+    /// Whether a number outside `min` and `max` is clamped to the nearest bound when the input loses focus.
+    /// When `false`, out-of-range values are left as typed.
+    #[prop_or_default]
+    pub clamp_on_blur: bool,
+
+    /// The larger step used with Shift+Arrow and PageUp/PageDown on number inputs. Defaults to ten times `step` when unset.
+    #[prop_or_default]
+    pub large_step: Option<f64>,
+
+    /// The `inputmode` attribute selecting the virtual keyboard on mobile, e.g. "numeric". Number inputs
+    /// default to "decimal" when `step` or `min` has decimal places and to "numeric" otherwise.
+    #[prop_or_default]
+    pub input_mode: &'static str,
+
+    /// Rich content shown over the field while it is empty and unfocused, e.g. an icon with a styled hint.
+    #[prop_or_default]
+    pub empty_state: Option<Html>,
+
+    /// Content rendered before the input inside the field, e.g. a currency symbol. It may be interactive,
+    /// its events are not handled by the input.
+    #[prop_or_default]
+    pub prefix: Option<Html>,
+
+    /// Content rendered after the input inside the field, e.g. a unit or a currency `<select>`. It may be
+    /// interactive, its events are not handled by the input.
+    #[prop_or_default]
+    pub suffix: Option<Html>,
+
     /// The state handle for managing the value of the input.
     pub input_handle: UseStateHandle<String>,
 
     /// The state handle for managing the validity state of the input.
     pub input_valid_handle: UseStateHandle<bool>,
 
+    /// An optional validity group shared with other inputs, valid only when all of its members are valid.
+    #[prop_or_default]
+    pub group_valid_handle: Option<ValidityGroup>,
+
     /// A callback function to validate the input value. It takes a `String` as input and returns a `bool`.
     pub validate_function: Callback<String, bool>,
 
+    /// Whether each call of `validate_function` is timed with `performance.now()` and reported through
+    /// `on_timing`, e.g. to find slow validators in large forms. Nothing is measured when unset.
+    #[prop_or_default]
+    pub debug_timing: bool,
+
+    /// A callback receiving the duration of each `validate_function` call in milliseconds, when
+    /// `debug_timing` is set. It's never called while rendering, so it can safely set state.
+    #[prop_or_default]
+    pub on_timing: Callback<f64>,
+
+    /// Whether the value is revalidated whenever `input_handle` changes, including when the parent sets it,
+    /// e.g. to pre-populate or reset the field. Untouched fields are only marked valid, never invalid. Values
+    /// the field's own handlers just validated, e.g. on each keystroke, aren't validated a second time.
+    #[prop_or(true)]
+    pub revalidate_on_change: bool,
+
+    /// A regular expression the whole value must match, also emitted as the `pattern` attribute.
+    #[prop_or_default]
+    pub pattern: &'static str,
+
+    /// A description of the format expected by `pattern`, e.g. "Five digits". Like the native `title`
+    /// convention, it is used as the `title` attribute and as the error message when `title` and
+    /// `error_message` are empty.
+    #[prop_or_default]
+    pub pattern_description: &'static str,
+
+    /// The minimum number of characters for the input to be considered valid, emitted as the `minlength` attribute.
+    #[prop_or_default]
+    pub min_length: Option<usize>,
+
+    /// The minimum number of words of a textarea value.
+    #[prop_or_default]
+    pub min_words: Option<usize>,
+
+    /// The maximum number of words of a textarea value.
+    #[prop_or_default]
+    pub max_words: Option<usize>,
+
+    /// Whether to display a word counter below textareas.
+    #[prop_or_default]
+    pub show_word_count: bool,
+
+    /// The error message displayed when a textarea has fewer than `min_words` or more than `max_words` words.
+    /// Falls back to `error_message` when empty.
+    #[prop_or_default]
+    pub word_count_message: &'static str,
+
+    /// The minimum strength score for the input to be considered valid. `0` accepts any strength.
+    #[prop_or_default]
+    pub min_strength: u8,
+
+    /// Whether to display a strength meter below password inputs. The meter is independent of validity.
+    #[prop_or_default]
+    pub show_strength: bool,
+
+    /// Labels for stages of password strength, e.g. `vec!["Weak", "Fair", "Strong"]`, spread evenly over the
+    /// scores of the strength function. The stages are listed below password inputs with the current one
+    /// marked, and the label of the current stage describes the strength meter.
+    #[prop_or_default]
+    pub strength_labels: Vec<&'static str>,
+
+    /// A callback scoring the input value from `0` to `4`. Defaults to `strength::password_strength`.
+    #[prop_or_default]
+    pub strength_function: Option<Callback<String, u8>>,
+
     /// The icon when the password is visible. Assuming fontawesome icons is used by default.
     #[prop_or("fa fa-eye")]
     pub eye_active: &'static str,
@@ -71,6 +536,108 @@ pub struct Props {
     #[prop_or("fa fa-eye-slash")]
     pub eye_disabled: &'static str,
 
+    /// Markup rendered as the icon when the password is visible, e.g. an inline SVG. Overrides `eye_active`.
+    #[prop_or_default]
+    pub eye_active_html: Option<Html>,
+
+    /// Markup rendered as the icon when the password is not visible. Overrides `eye_disabled`.
+    #[prop_or_default]
+    pub eye_disabled_html: Option<Html>,
+
+    /// The message announced to screen readers when the password is revealed.
+    #[prop_or("Password shown")]
+    pub password_shown_message: &'static str,
+
+    /// The message announced to screen readers when the password is hidden again.
+    #[prop_or("Password hidden")]
+    pub password_hidden_message: &'static str,
+
+    /// Whether the decorative icon receives pointer events. By default clicks pass through it to the input.
+    #[prop_or_default]
+    pub icon_clickable: bool,
+
+    /// Whether a button emptying the field is displayed while it has a value.
+    #[prop_or_default]
+    pub clearable: bool,
+
+    /// The CSS class to be applied to the clear button.
+    #[prop_or_default]
+    pub form_input_clear_class: &'static str,
+
+    /// A callback fired when the clear button empties the field, e.g. to reset search results.
+    #[prop_or_default]
+    pub on_clear: Callback<()>,
+
+    /// Forwards the field name and error message to the consumer instead of rendering the error inline, e.g. to
+    /// show it in a toast or a summary. An empty message means the error was resolved.
+    #[prop_or_default]
+    pub error_sink: Option<Callback<(String, String)>>,
+
+    /// Whether to render an inline error/success icon inside the field.
+    #[prop_or_default]
+    pub validation_icon: bool,
+
+    /// The inline icon when the input is invalid. Assuming fontawesome icons is used by default.
+    #[prop_or("fa fa-exclamation-circle")]
+    pub error_icon: &'static str,
+
+    /// The inline icon when the input is valid. Assuming fontawesome icons is used by default.
+    #[prop_or("fa fa-check-circle")]
+    pub success_icon: &'static str,
+
+    /// A hint displayed below the field, e.g. "At least 8 characters".
+    #[prop_or_default]
+    pub helper_text: &'static str,
+
+    /// The CSS class to be applied to the helper text.
+    #[prop_or_default]
+    pub form_input_helper_class: &'static str,
+
+    /// The number of attempts left on a rate-limited field, e.g. a login or one-time code, announced to
+    /// screen readers as "2 attempts remaining". Nothing is rendered when unset.
+    #[prop_or_default]
+    pub attempts_remaining: Option<u32>,
+
+    /// The CSS class to be applied to the remaining attempts message.
+    #[prop_or_default]
+    pub form_input_attempts_class: &'static str,
+
+    /// How the helper text and the error message are combined: "stack" displays both, while "replace"
+    /// hides the helper text while the error is displayed.
+    #[prop_or("stack")]
+    pub helper_error_mode: &'static str,
+
+    /// Whether to display the error message text below the field when the input is invalid.
+    #[prop_or(true)]
+    pub show_error_text: bool,
+
+    /// Whether an asynchronous check of the value is in progress, e.g. looking up whether a username is
+    /// taken. The field is marked `aria-busy` meanwhile, and once it's cleared the outcome is announced to
+    /// screen readers: the error message when `input_valid_handle` was set to invalid, otherwise
+    /// `async_success_message`.
+    #[prop_or_default]
+    pub validation_pending: bool,
+
+    /// The message announced when a pending asynchronous check passes, e.g. "Username available".
+    #[prop_or_default]
+    pub async_success_message: &'static str,
+
+    /// Overrides when the error is displayed, e.g. `Some(submitted && !valid)` to only show it after a failed
+    /// submit. When `None`, the error is displayed whenever the input is invalid.
+    #[prop_or_default]
+    pub show_error: Option<bool>,
+
+    /// A counter to bump, e.g. from a `UseStateHandle<u32>`, to mark the field untouched and valid while
+    /// keeping its value, such as when dismissing a server error so the user can retry. Unlike a full
+    /// reset, `input_handle` is left intact. The initial `0` clears nothing.
+    #[prop_or_default]
+    pub clear_errors: u32,
+
+    /// Whether the validation result is mirrored to the native constraint validation API with
+    /// `setCustomValidity`, so that native form submission is blocked while the input is invalid.
+    #[prop_or_default]
+    pub use_native_validity: bool,
+
     // Additional props for accessibility and SEO:
     /// The ID attribute of the input element.
     #[prop_or_default]
@@ -80,6 +647,22 @@ pub struct Props {
     #[prop_or_default]
     pub input_placeholder: &'static str,
 
+    /// The autocomplete attribute, passed through unchanged so that multiple tokens such as
+    /// "section-billing address-line1" work. See the `autocomplete` module for common tokens.
+    #[prop_or_default]
+    pub autocomplete: &'static str,
+
+    /// Whether browser autofill is disabled, for sensitive fields such as bank account numbers. Sets
+    /// `autocomplete="off"` ("new-password" for passwords, as browsers ignore "off" there) and keeps the
+    /// input read-only until focused, which most password managers skip. Autofill can't be fully
+    /// prevented, as browsers and extensions are free to ignore these hints.
+    #[prop_or_default]
+    pub disable_autofill: bool,
+
+    /// The title attribute of the input element, shown by browsers as a native tooltip.
+    #[prop_or_default]
+    pub title: &'static str,
+
     /// The aria-label attribute for screen readers, providing a label for accessibility.
     #[prop_or_default]
     pub aria_label: &'static str,
@@ -92,11 +675,135 @@ pub struct Props {
     #[prop_or("true")]
     pub aria_invalid: &'static str,
 
+    /// A label announcing the container as a group, e.g. "Phone number" for the tel country and number pair.
+    /// When set, the container gets `role="group"`.
+    #[prop_or_default]
+    pub group_label: &'static str,
+
     /// The aria-describedby attribute for screen readers, describing the input element's error message.
     #[prop_or_default]
     pub aria_describedby: &'static str,
+
+    /// Renders completely custom markup for the field, reusing the state, validation and callbacks of the
+    /// component. When set, it replaces the default rendering entirely.
+    #[prop_or_default]
+    pub render: Option<Callback<FieldRenderArgs, Html>>,
+}
+
+/// Everything needed to render a field with custom markup through the `render` prop.
+#[derive(Clone, PartialEq)]
+pub struct FieldRenderArgs {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The ID of the input element.
+    pub input_id: &'static str,
+    /// The current value of the field.
+    pub value: String,
+    /// Whether the current value is valid.
+    pub valid: bool,
+    /// Whether the error should be displayed, see the `show_error` prop.
+    pub error_visible: bool,
+    /// The error message of the field.
+    pub error_message: &'static str,
+    /// Every check the last validated value failed, including `min_strength`.
+    pub errors: Vec<ValidationError>,
+    /// The reference to attach to the input element, which the callbacks read the value from.
+    pub input_ref: NodeRef,
+    /// The `oninput` handler of the input element.
+    pub oninput: Callback<InputEvent>,
+    /// The `onchange` handler of the input element, updating the value instead of `oninput` when
+    /// `validate_on` is `ValidationTrigger::Change`.
+    pub onchange: Callback<Event>,
+    /// The `onfocus` handler of the input element.
+    pub onfocus: Callback<FocusEvent>,
+    /// The `onblur` handler of the input element.
+    pub onblur: Callback<FocusEvent>,
+    /// The `onkeydown` handler of the input element.
+    pub onkeydown: Callback<KeyboardEvent>,
 }
 
+/// Inline styles hiding an element visually while keeping it available to screen readers.
+pub(crate) const VISUALLY_HIDDEN_STYLE: &str = "position: absolute; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; border: 0;";
+
+/// A minimal baseline stylesheet, opt-in by injecting it in a `<style>` tag once per page and setting
+/// the `input-yew*` classes on the matching class props. Selectors use `:where()` so that any framework
+/// or custom style overrides them.
+///
+/// | Class | Prop |
+/// | --- | --- |
+/// | `input-yew` | `form_input_class` |
+/// | `input-yew-label` | `form_input_label_class` |
+/// | `input-yew-field` | `form_input_field_class` |
+/// | `input-yew-input` | `form_input_input_class` |
+/// | `input-yew-error` | `form_input_error_class` |
+///
+/// # Examples
+/// ```
+/// use input_yew::DEFAULT_CSS;
+/// use yew::prelude::*;
+///
+/// let styles = html! { <style>{ DEFAULT_CSS }</style> };
+/// ```
+pub const DEFAULT_CSS: &str = r#":where(.input-yew) {
+  display: flex;
+  flex-direction: column;
+  gap: 0.25rem;
+  margin-bottom: 1rem;
+  font-family: inherit;
+}
+:where(.input-yew-label) {
+  font-weight: 600;
+}
+:where(.input-yew-field) {
+  position: relative;
+  display: flex;
+  align-items: center;
+}
+:where(.input-yew-input) {
+  flex: 1;
+  padding: 0.5rem 0.75rem;
+  font: inherit;
+  border: 1px solid #ccc;
+  border-radius: 0.375rem;
+}
+:where(.input-yew-input:focus) {
+  outline: 2px solid #2563eb;
+  outline-offset: 1px;
+}
+:where(.input-yew-error) {
+  color: var(--input-error-color, #dc2626);
+  font-size: 0.875rem;
+}
+:where(.input-yew .toggle-button) {
+  position: absolute;
+  right: 0.75rem;
+  cursor: pointer;
+  background: none;
+  border: 0;
+}
+:where(.input-yew button:focus-visible) {
+  outline: 2px solid #2563eb;
+  outline-offset: 1px;
+}
+"#;
+
+/// The height of an option of the searchable country list, in pixels. Only the options scrolled into view
+/// are rendered, so the styles of the list must keep this height.
+#[cfg(feature = "phone")]
+const COUNTRY_OPTION_HEIGHT: f64 = 32.0;
+
+/// The number of options visible at once in the searchable country list.
+#[cfg(feature = "phone")]
+const COUNTRY_LIST_ROWS: usize = 8;
+
+/// The number of options rendered beyond each edge of the visible ones, so that scrolling doesn't reveal
+/// blank rows.
+#[cfg(feature = "phone")]
+const COUNTRY_LIST_BUFFER: usize = 4;
+
+/// The radius of the circular character counter, in SVG user units.
+const COUNTER_RING_RADIUS: f64 = 10.0;
+
 /// custom_input_component
 /// A custom input component that handles user input and validation.
 ///
@@ -222,69 +929,795 @@ pub fn custom_input(props: &Props) -> Html {
     let eye_active_handle = use_state(|| false);
     let eye_active = *eye_active_handle;
 
+    #[cfg(feature = "phone")]
     let input_country_ref = use_node_ref();
-    let country_handle = use_state(String::default);
-    let country = (*country_handle).clone();
+    #[cfg(feature = "phone")]
+    let country_handle = use_state(|| None::<Country>);
+    #[cfg(feature = "phone")]
+    let country = *country_handle;
+    #[cfg(feature = "phone")]
+    let countries: &'static [Country] = props.countries.unwrap_or(&COUNTRY_CODES);
 
     let password_type_handle = use_state(|| "password");
     let password_type = *password_type_handle;
 
-    let input_valid = *props.input_valid_handle;
+    // Nothing is announced until the visibility is toggled
+    let password_toggled_handle = use_state(|| false);
+    let password_toggled = *password_toggled_handle;
 
-    let aria_invalid = props.aria_invalid;
+    let touched_handle = use_state(|| false);
+    let touched = *touched_handle;
 
-    let eye_icon_active = props.eye_active;
+    let focused_handle = use_state(|| false);
+    let focused = *focused_handle;
 
-    let eye_icon_disabled = props.eye_disabled;
+    let history = use_mut_ref(UndoHistory::default);
 
-    let aria_required = props.aria_required;
+    // Set once a pending asynchronous check completes, so that its outcome is announced
+    let async_settled_handle = use_state(|| false);
+    let async_settled = *async_settled_handle;
 
-    let input_type = props.input_type;
+    {
+        let was_pending = use_mut_ref(|| props.validation_pending);
+        let async_settled_handle = async_settled_handle.clone();
+        use_effect_with(props.validation_pending, move |pending| {
+            let was_pending = was_pending.replace(*pending);
+            if *pending {
+                async_settled_handle.set(false);
+            } else if was_pending {
+                async_settled_handle.set(true);
+            }
+        });
+    }
 
-    let onchange = {
-        let input_ref = props.input_ref.clone();
-        let input_handle = props.input_handle.clone();
+    {
+        let previous_name = use_mut_ref(|| props.name);
+        let touched_handle = touched_handle.clone();
+        let eye_active_handle = eye_active_handle.clone();
+        let password_type_handle = password_type_handle.clone();
+        let password_toggled_handle = password_toggled_handle.clone();
         let input_valid_handle = props.input_valid_handle.clone();
-        let validate_function = props.validate_function.clone();
-
-        Callback::from(move |_| {
-            if let Some(input) = input_ref.cast::<HtmlInputElement>() {
-                let value = input.value();
-                input_handle.set(value);
-                input_valid_handle.set(validate_function.emit(input.value()));
+        let async_settled_handle = async_settled_handle.clone();
+        let history = history.clone();
+        use_effect_with(props.name, move |name| {
+            // A reused instance, e.g. in a dynamic list, now stands for another field
+            if previous_name.replace(*name) != *name {
+                touched_handle.set(false);
+                eye_active_handle.set(false);
+                password_type_handle.set("password");
+                password_toggled_handle.set(false);
+                input_valid_handle.set(true);
+                async_settled_handle.set(false);
+                *history.borrow_mut() = UndoHistory::default();
+            }
+        });
+    }
+
+    {
+        let history = history.clone();
+        use_effect_with((*props.input_handle).clone(), move |value| {
+            history.borrow_mut().record(value);
+        });
+    }
+
+    let input_valid = *props.input_valid_handle;
+
+    // Natively, the `title` of a field with a `pattern` describes the expected format.
+    let pattern_message = if props.pattern.is_empty() {
+        ""
+    } else if !props.pattern_description.is_empty() {
+        props.pattern_description
+    } else {
+        props.title
+    };
+    let title = if props.title.is_empty() { pattern_message } else { props.title };
+    let default_error_message = if props.error_message.is_empty() {
+        pattern_message
+    } else {
+        props.error_message
+    };
+
+    {
+        let touched_handle = touched_handle.clone();
+        let input_valid_handle = props.input_valid_handle.clone();
+        use_effect_with(props.clear_errors, move |clear_errors| {
+            if *clear_errors > 0 {
+                touched_handle.set(false);
+                input_valid_handle.set(true);
+            }
+        });
+    }
+
+    {
+        let group_valid_handle = props.group_valid_handle.clone();
+        use_effect_with((props.name, input_valid), move |(name, valid)| {
+            let name = *name;
+            if let Some(group) = &group_valid_handle {
+                group.dispatch(ValidityGroupAction::Set(name, *valid));
+            }
+            move || {
+                if let Some(group) = group_valid_handle {
+                    group.dispatch(ValidityGroupAction::Remove(name));
+                }
+            }
+        });
+    }
+
+    let form = use_context::<FormContext>();
+
+    let required = match (&props.required_when, &form) {
+        (Some(required_when), Some(form)) => required_when.emit(form.values()),
+        _ => props.required,
+    };
+
+    {
+        let form = form.clone();
+        use_effect_with(props.name, move |name| {
+            let name = *name;
+            move || {
+                if let Some(form) = form {
+                    form.dispatch(FormAction::Unregister(name));
+                }
+            }
+        });
+    }
+
+    {
+        let form = form.clone();
+        let field = FieldState {
+            required,
+            valid: input_valid,
+            value: (*props.input_handle).clone(),
+            input_ref: props.input_ref.clone(),
+        };
+        use_effect_with((props.name, field), move |(name, field)| {
+            if let Some(form) = form {
+                form.dispatch(FormAction::Register(name, field.clone()));
+            }
+        });
+    }
+
+    let aria_invalid = props.aria_invalid;
+
+    let eye_icon_active = props.eye_active;
+
+    let eye_icon_disabled = props.eye_disabled;
+
+    let aria_required = match props.required_when {
+        Some(_) if required => "true",
+        Some(_) => "false",
+        None => props.aria_required,
+    };
+
+    let input_type = props.input_type;
+
+    let strength_function = props
+        .strength_function
+        .clone()
+        .unwrap_or_else(|| Callback::from(password_strength));
+
+    let validation_options = ValidationOptions {
+        required,
+        min_length: props.min_length,
+        max_length: props.max_length,
+        count_graphemes: props.count_graphemes,
+        pattern: (!props.pattern.is_empty()).then_some(props.pattern),
+        min: props.min.filter(|_| matches!(input_type, "number" | "range")),
+        max: props.max.filter(|_| matches!(input_type, "number" | "range")),
+        step: props.step.filter(|_| input_type == "number"),
+        min_words: props.min_words.filter(|_| input_type == "textarea"),
+        max_words: props.max_words.filter(|_| input_type == "textarea"),
+        allow_empty: props.allow_empty.unwrap_or(!required),
+    };
+
+    // The last value passed to `validate`, so that the revalidation effect skips values the handlers just validated
+    let last_validated = use_mut_ref(|| None::<String>);
+
+    // The outcome of the last validation, from which the displayed errors are derived
+    let validation_handle = use_state_eq(ValidationState::default);
+    let validation = (*validation_handle).clone();
+
+    // Gates submission: the length and strength thresholds accept a value, while the strength meter only
+    // reflects it, so a weak-but-acceptable password still passes.
+    let validate = {
+        let validation_handle = validation_handle.clone();
+        let last_validated = last_validated.clone();
+        // Only the validation run by the handlers and effects is timed, never the one during rendering
+        let validate_function = if props.debug_timing {
+            let validate_function = props.validate_function.clone();
+            let on_timing = props.on_timing.clone();
+            let performance = web_sys::window().and_then(|window| window.performance());
+            Callback::from(move |value: String| match &performance {
+                Some(performance) => {
+                    let start = performance.now();
+                    let valid = validate_function.emit(value);
+                    on_timing.emit(performance.now() - start);
+                    valid
+                }
+                None => validate_function.emit(value),
+            })
+        } else {
+            props.validate_function.clone()
+        };
+        let strength_function = strength_function.clone();
+        let validation_options = validation_options.clone();
+        let min_strength = props.min_strength;
+
+        Callback::from(move |value: String| {
+            *last_validated.borrow_mut() = Some(value.clone());
+            let skipped = validation_options.allow_empty && value.trim().is_empty();
+            let strong_enough =
+                min_strength == 0 || skipped || strength_function.emit(value.clone()) >= min_strength;
+            let mut validation = validate_value(&value, &validate_function, &validation_options);
+            if !strong_enough {
+                validation.errors.push(ValidationError::TooWeak);
             }
+            validation_handle.set(validation.clone());
+            validation
         })
     };
 
-    let on_select_change = {
-        let input_country_ref = input_country_ref.clone();
+    // Debounced validation runs once the value settles, after user edits and outside changes alike
+    let settled_value = use_debounce((*props.input_handle).clone(), props.debounce_ms);
+
+    // With `ValidationTrigger::Blur`, edits are only validated once the user leaves the field
+    let validates_edits = props.validate_on != ValidationTrigger::Blur;
+    // Whether the handlers validate edits themselves, rather than leaving them to the debounce or to blur
+    let validate_edits_now = validates_edits && props.debounce_ms == 0;
+
+    {
+        let input_valid_handle = props.input_valid_handle.clone();
+        let validate = validate.clone();
+        let revalidate = props.revalidate_on_change && props.debounce_ms == 0;
+        use_effect_with((*props.input_handle).clone(), move |value| {
+            let validated = last_validated.borrow().as_ref() == Some(value);
+            if revalidate && !validated && (validates_edits || !touched) {
+                let valid = validate.emit(value.clone()).is_valid();
+                // A pre-populated or reset field doesn't show an error before the user edits it
+                if valid != input_valid && (touched || valid) {
+                    input_valid_handle.set(valid);
+                }
+            }
+        });
+    }
+
+    {
+        let input_valid_handle = props.input_valid_handle.clone();
+        let validate = validate.clone();
+        let debounced = props.debounce_ms > 0;
+        let revalidate = props.revalidate_on_change;
+        use_effect_with(settled_value.clone(), move |value| {
+            if debounced && (touched || revalidate) && (validates_edits || !touched) {
+                let valid = validate.emit(value.clone()).is_valid();
+                if valid != input_valid && (touched || valid) {
+                    input_valid_handle.set(valid);
+                }
+            }
+        });
+    }
+
+    {
+        let input_ref = props.input_ref.clone();
+        // An empty custom validity marks the element as valid, so a message is always provided.
+        let message = if !default_error_message.is_empty() {
+            default_error_message
+        } else {
+            validation.errors.first().map_or(ValidationError::Custom.message(), |error| error.message())
+        };
+        use_effect_with(
+            (props.use_native_validity, input_valid, message),
+            move |(use_native_validity, valid, message)| {
+                if *use_native_validity {
+                    let message = if *valid { "" } else { *message };
+                    if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                        input.set_custom_validity(message);
+                    } else if let Some(textarea) = input_ref.cast::<HtmlTextAreaElement>() {
+                        textarea.set_custom_validity(message);
+                    }
+                }
+            },
+        );
+    }
+
+    {
+        let input_valid_handle = props.input_valid_handle.clone();
+        let validate = validate.clone();
+        let value = (*props.input_handle).clone();
+        // Another field can make this one required or optional after it was edited
+        use_effect_with(required, move |_| {
+            if touched {
+                input_valid_handle.set(validate.emit(value).is_valid());
+            }
+        });
+    }
+
+    let update_value = {
+        let input_ref = props.input_ref.clone();
+        let input_handle = props.input_handle.clone();
+        let input_valid_handle = props.input_valid_handle.clone();
+        let validate = validate.clone();
+        let handling = ValueHandling {
+            parse: props.parse.clone(),
+            display_format: props.display_format.clone(),
+            value_middleware: props.value_middleware.clone(),
+            max_decimals: props.max_decimals,
+            decimal_separator: props.decimal_separator,
+            // The native `maxlength` counts UTF-16 code units, so grapheme limits are enforced here instead.
+            grapheme_limit: props
+                .max_length
+                .filter(|_| props.count_graphemes && props.enforce_max_length),
+            on_files_meta: props.on_files_meta.clone(),
+            touched_handle: Some(touched_handle.clone()),
+        };
+
+        Callback::from(move |()| {
+            if let Some(element) = input_ref.cast::<Element>() {
+                let validate = validate_edits_now.then_some(&validate);
+                update_field(&element, &handling, &input_handle, &input_valid_handle, validate);
+            }
+        })
+    };
+
+    // Live updates listen to `input`, committed ones to `change`
+    let (oninput, onchange) = match props.validate_on {
+        ValidationTrigger::Input | ValidationTrigger::Blur | ValidationTrigger::Both => {
+            (update_value.reform(|_: InputEvent| ()), Callback::noop())
+        }
+        ValidationTrigger::Change => (Callback::noop(), update_value.reform(|_: Event| ())),
+    };
+    let onchange = {
+        let on_raw_change = props.on_raw_change.clone();
+        Callback::from(move |event: Event| {
+            on_raw_change.emit(event.clone());
+            onchange.emit(event);
+        })
+    };
+
+    #[cfg(feature = "phone")]
+    {
         let input_handle = props.input_handle.clone();
         let country_handle = country_handle.clone();
+        let on_phone_change = props.on_phone_change.clone();
+        let include_plus = props.tel_include_plus;
+        use_effect_with(props.initial_phone, move |initial_phone| {
+            if initial_phone.is_empty() {
+                return;
+            }
+            if let Some(phone) = parse_phone_number_in(countries, initial_phone, None) {
+                country_handle.set(Some(phone.country));
+                input_handle.set(stored_tel_value(&phone.e164, include_plus));
+                on_phone_change.emit(phone);
+            }
+        });
+    }
+
+    #[cfg(feature = "phone")]
+    let select_country = {
+        let input_handle = props.input_handle.clone();
+        let country_handle = country_handle.clone();
+        let on_phone_change = props.on_phone_change.clone();
+        let include_plus = props.tel_include_plus;
+        Callback::from(move |selected: Country| {
+            if let Some(phone) = parse_phone_number_in(countries, selected.code, Some(&selected)) {
+                on_phone_change.emit(phone);
+            }
+            country_handle.set(Some(selected));
+            input_handle.set(stored_tel_value(selected.code, include_plus));
+        })
+    };
+
+    #[cfg(feature = "phone")]
+    let on_select_change = {
+        let input_country_ref = input_country_ref.clone();
+        let select_country = select_country.clone();
         Callback::from(move |_| {
-            if let Some(input) = input_country_ref.cast::<HtmlInputElement>() {
-                let value = input.value();
-                country_handle.set(value);
-                input_handle.set(input.value());
+            if let Some(select) = input_country_ref.cast::<HtmlSelectElement>() {
+                let name = select.value();
+                if let Some(selected) = countries.iter().find(|country| country.name == name) {
+                    select_country.emit(*selected);
+                }
+            }
+        })
+    };
+
+    #[cfg(feature = "phone")]
+    let country_query_handle = use_state(String::default);
+    #[cfg(feature = "phone")]
+    let country_list_open_handle = use_state(|| false);
+    #[cfg(feature = "phone")]
+    let country_list_open = *country_list_open_handle;
+    #[cfg(feature = "phone")]
+    let country_active_handle = use_state(|| 0_usize);
+    // The matching countries are only searched while the list is open, and cached between the renders
+    // caused by scrolling it
+    #[cfg(feature = "phone")]
+    let country_matches = use_memo(
+        (countries, (*country_query_handle).clone(), country_list_open, props.country_sort.clone()),
+        |(countries, query, open, sort)| {
+            if *open {
+                let mut matches = search_countries_in(countries, query);
+                sort_countries(&mut matches, sort);
+                matches
+            } else {
+                Vec::new()
+            }
+        },
+    );
+    #[cfg(feature = "phone")]
+    let country_options_requested_handle = use_state(|| false);
+    #[cfg(feature = "phone")]
+    let all_country_options = !props.defer_country_list || *country_options_requested_handle;
+    #[cfg(feature = "phone")]
+    let country_options: Vec<&Country> = if all_country_options {
+        let mut options: Vec<&Country> = countries.iter().collect();
+        sort_countries(&mut options, &props.country_sort);
+        options
+    } else {
+        country.as_ref().or(countries.first()).into_iter().collect()
+    };
+    #[cfg(feature = "phone")]
+    let request_country_options = {
+        let country_options_requested_handle = country_options_requested_handle.clone();
+        Callback::from(move |()| {
+            if !*country_options_requested_handle {
+                country_options_requested_handle.set(true);
+            }
+        })
+    };
+    #[cfg(feature = "phone")]
+    let country_active = (*country_active_handle).min(country_matches.len().saturating_sub(1));
+    #[cfg(feature = "phone")]
+    let country_list_id = format!("{}-countries", props.name);
+    #[cfg(feature = "phone")]
+    let country_list_ref = use_node_ref();
+    #[cfg(feature = "phone")]
+    let country_scroll_handle = use_state(|| 0.0_f64);
+    #[cfg(feature = "phone")]
+    let country_scroll = *country_scroll_handle;
+    #[cfg(feature = "phone")]
+    let loaded_flags = use_mut_ref(HashSet::<&'static str>::new);
+    #[cfg(feature = "phone")]
+    let flags_loaded = use_force_update();
+
+    #[cfg(feature = "phone")]
+    let pick_country = {
+        let country_query_handle = country_query_handle.clone();
+        let country_list_open_handle = country_list_open_handle.clone();
+        Callback::from(move |selected: Country| {
+            select_country.emit(selected);
+            country_query_handle.set(String::new());
+            country_list_open_handle.set(false);
+        })
+    };
+
+    #[cfg(feature = "phone")]
+    let on_country_query_input = {
+        let country_query_handle = country_query_handle.clone();
+        let country_list_open_handle = country_list_open_handle.clone();
+        let country_active_handle = country_active_handle.clone();
+        let country_scroll_handle = country_scroll_handle.clone();
+        Callback::from(move |event: InputEvent| {
+            let input: HtmlInputElement = event.target_unchecked_into();
+            country_query_handle.set(input.value());
+            country_list_open_handle.set(true);
+            country_active_handle.set(0);
+            country_scroll_handle.set(0.0);
+        })
+    };
+
+    #[cfg(feature = "phone")]
+    let on_country_list_scroll = {
+        let country_scroll_handle = country_scroll_handle.clone();
+        Callback::from(move |event: Event| {
+            let list: HtmlElement = event.target_unchecked_into();
+            country_scroll_handle.set(list.scroll_top() as f64);
+        })
+    };
+
+    #[cfg(feature = "phone")]
+    {
+        let country_list_ref = country_list_ref.clone();
+        use_effect_with((country_scroll, country_list_open), move |(country_scroll, _)| {
+            // Keeps the list in sync when the keyboard moves the active option out of view
+            if let Some(list) = country_list_ref.cast::<HtmlElement>() {
+                if (list.scroll_top() as f64 - country_scroll).abs() >= 1.0 {
+                    list.set_scroll_top(*country_scroll as i32);
+                }
+            }
+        });
+    }
+
+    #[cfg(feature = "phone")]
+    let on_country_query_keydown = {
+        let country_list_open_handle = country_list_open_handle.clone();
+        let country_active_handle = country_active_handle.clone();
+        let country_scroll_handle = country_scroll_handle.clone();
+        let pick_country = pick_country.clone();
+        let country_matches = country_matches.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            let last = country_matches.len().saturating_sub(1);
+            // Scrolls the newly active option into view, so that it is rendered for `aria-activedescendant`
+            let activate = |index: usize| {
+                country_active_handle.set(index);
+                country_scroll_handle.set(country_scroll_to(country_scroll, index));
+            };
+            match event.key().as_str() {
+                "ArrowDown" => {
+                    event.prevent_default();
+                    if country_list_open {
+                        activate((country_active + 1).min(last));
+                    }
+                    country_list_open_handle.set(true);
+                }
+                "ArrowUp" => {
+                    event.prevent_default();
+                    activate(country_active.saturating_sub(1));
+                    country_list_open_handle.set(true);
+                }
+                "Home" if country_list_open => {
+                    event.prevent_default();
+                    activate(0);
+                }
+                "End" if country_list_open => {
+                    event.prevent_default();
+                    activate(last);
+                }
+                "Enter" if country_list_open => {
+                    if let Some(selected) = country_matches.get(country_active) {
+                        event.prevent_default();
+                        pick_country.emit(**selected);
+                    }
+                }
+                "Escape" => country_list_open_handle.set(false),
+                _ => {}
             }
         })
     };
 
+    #[cfg(feature = "phone")]
+    let on_country_query_focus = {
+        let country_list_open_handle = country_list_open_handle.clone();
+        Callback::from(move |_: FocusEvent| country_list_open_handle.set(true))
+    };
+
+    #[cfg(feature = "phone")]
+    let on_country_query_blur = {
+        let country_list_open_handle = country_list_open_handle.clone();
+        Callback::from(move |_: FocusEvent| country_list_open_handle.set(false))
+    };
+
+    #[cfg(feature = "phone")]
     let on_phone_number_input = {
         let input_ref = props.input_ref.clone();
         let input_handle = props.input_handle.clone();
-        let country_handle = country_handle;
+        let country_handle = country_handle.clone();
+        let touched_handle = touched_handle.clone();
+        let on_phone_change = props.on_phone_change.clone();
+        let include_plus = props.tel_include_plus;
         Callback::from(move |_| {
             if let Some(input) = input_ref.cast::<HtmlInputElement>() {
-                for (code, _, _, _, _, _) in &COUNTRY_CODES {
-                    if code.starts_with(&input.value()) {
-                        country_handle.set(input.value());
-                        break;
-                    }
-                }
                 // Filter out non-numeric characters
                 let numeric_value: String =
                     input.value().chars().filter(|c| c.is_numeric()).collect();
-                input_handle.set('+'.to_string() + &numeric_value);
+                let value = '+'.to_string() + &numeric_value;
+                if let Some(phone) = parse_phone_number_in(countries, &value, country.as_ref()) {
+                    country_handle.set(Some(phone.country));
+                    on_phone_change.emit(phone);
+                }
+                input_handle.set(stored_tel_value(&value, include_plus));
+                touched_handle.set(true);
+            }
+        })
+    };
+
+    #[cfg(feature = "phone")]
+    let on_phone_paste = {
+        let input_handle = props.input_handle.clone();
+        let country_handle = country_handle;
+        let touched_handle = touched_handle.clone();
+        let on_phone_change = props.on_phone_change.clone();
+        let include_plus = props.tel_include_plus;
+        Callback::from(move |event: Event| {
+            let pasted = event
+                .dyn_ref::<ClipboardEvent>()
+                .and_then(ClipboardEvent::clipboard_data)
+                .and_then(|data| data.get_data("text").ok())
+                .unwrap_or_default();
+            // Only international numbers carry a dialing code to split off
+            if !pasted.trim_start().starts_with('+') {
+                return;
+            }
+            if let Some(phone) = parse_phone_number_in(countries, &pasted, country.as_ref()) {
+                event.prevent_default();
+                country_handle.set(Some(phone.country));
+                input_handle.set(stored_tel_value(&phone.e164, include_plus));
+                on_phone_change.emit(phone);
+                touched_handle.set(true);
+            }
+        })
+    };
+
+    let on_number_keydown = {
+        let input_handle = props.input_handle.clone();
+        let input_valid_handle = props.input_valid_handle.clone();
+        let validate = validate.clone();
+        let touched_handle = touched_handle.clone();
+        let (min, max) = (props.min, props.max);
+        let step = props.step.unwrap_or(1.0);
+        let large_step = props.large_step.unwrap_or(step * 10.0);
+
+        Callback::from(move |event: KeyboardEvent| {
+            let delta = match (event.key().as_str(), event.shift_key()) {
+                ("ArrowUp", false) => step,
+                ("ArrowUp", true) | ("PageUp", _) => large_step,
+                ("ArrowDown", false) => -step,
+                ("ArrowDown", true) | ("PageDown", _) => -large_step,
+                _ => return,
+            };
+            event.prevent_default();
+
+            let current = input_handle.parse::<f64>().unwrap_or(min.unwrap_or(0.0));
+            let mut next = current + delta;
+            if let Some(min) = min {
+                next = next.max(min);
+            }
+            if let Some(max) = max {
+                next = next.min(max);
+            }
+
+            // Round to the precision of the step to avoid float artifacts like `0.30000000000000004`
+            let value = format!("{:.*}", decimal_places(step), next);
+            input_handle.set(value.clone());
+            if validate_edits_now {
+                input_valid_handle.set(validate.emit(value).is_valid());
+            }
+            touched_handle.set(true);
+        })
+    };
+
+    let on_number_wheel = {
+        let disable_wheel = props.disable_wheel;
+        Callback::from(move |event: WheelEvent| {
+            if disable_wheel && focused {
+                event.prevent_default();
+            }
+        })
+    };
+
+    {
+        let input_ref = props.input_ref.clone();
+        let input_valid_handle = props.input_valid_handle.clone();
+        let validate = validate.clone();
+        use_effect_with(
+            (props.validate_interval_ms, focused && touched),
+            move |(interval_ms, active)| {
+                let interval = (*active && *interval_ms > 0).then(|| {
+                    Interval::new(*interval_ms, move || {
+                        if let Some(value) = read_value(&input_ref) {
+                            input_valid_handle.set(validate.emit(value).is_valid());
+                        }
+                    })
+                });
+                move || drop(interval)
+            },
+        );
+    }
+
+    {
+        let on_complete = props.on_complete.clone();
+        let complete_length = props.auto_complete_at_length;
+        let count_graphemes = props.count_graphemes;
+        use_effect_with((*props.input_handle).clone(), move |value| {
+            if touched && complete_length == Some(text_length(value, count_graphemes)) {
+                on_complete.emit(value.clone());
+            }
+        });
+    }
+
+    {
+        let on_named_change = props.on_named_change.clone();
+        let name = props.name;
+        // Reacting to the value rather than to each handler covers every input type
+        use_effect_with(((*props.input_handle).clone(), input_valid), move |(value, valid)| {
+            if touched {
+                on_named_change.emit((name, value.clone(), *valid));
+            }
+        });
+    }
+
+    let on_keydown = {
+        let input_handle = props.input_handle.clone();
+        let input_valid_handle = props.input_valid_handle.clone();
+        let validate = validate.clone();
+        let enable_undo = props.enable_undo;
+        let is_number = input_type == "number";
+
+        Callback::from(move |event: KeyboardEvent| {
+            if enable_undo && (event.ctrl_key() || event.meta_key()) {
+                let restored = match event.key().to_lowercase().as_str() {
+                    "z" if !event.shift_key() => Some(history.borrow_mut().undo()),
+                    "z" | "y" => Some(history.borrow_mut().redo()),
+                    _ => None,
+                };
+                if let Some(restored) = restored {
+                    // The native undo stack is out of sync with programmatic rewrites, so always take over.
+                    event.prevent_default();
+                    if let Some(value) = restored {
+                        input_handle.set(value.clone());
+                        if validate_edits_now {
+                            input_valid_handle.set(validate.emit(value).is_valid());
+                        }
+                    }
+                    return;
+                }
+            }
+            if is_number {
+                on_number_keydown.emit(event);
+            }
+        })
+    };
+
+    let on_focus = {
+        let focused_handle = focused_handle.clone();
+        Callback::from(move |_: FocusEvent| focused_handle.set(true))
+    };
+
+    let on_blur = {
+        let focused_handle = focused_handle.clone();
+        let input_handle = props.input_handle.clone();
+        let input_valid_handle = props.input_valid_handle.clone();
+        let validate = validate.clone();
+        let collapse_whitespace = props.collapse_whitespace;
+        let (min, max) = (props.min, props.max);
+        let clamp_on_blur = props.clamp_on_blur && input_type == "number";
+        let validate_on_blur =
+            matches!(props.validate_on, ValidationTrigger::Blur | ValidationTrigger::Both);
+        Callback::from(move |_: FocusEvent| {
+            focused_handle.set(false);
+            let mut value = (*input_handle).clone();
+            // Normalizing on blur rather than on input keeps the spaces being typed
+            if collapse_whitespace {
+                value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+            }
+            if clamp_on_blur {
+                if let Ok(number) = value.trim().parse::<f64>() {
+                    let clamped = number.max(min.unwrap_or(f64::MIN)).min(max.unwrap_or(f64::MAX));
+                    if clamped != number {
+                        value = clamped.to_string();
+                    }
+                }
+            }
+            if value != *input_handle {
+                input_valid_handle.set(validate.emit(value.clone()).is_valid());
+                input_handle.set(value);
+            } else if validate_on_blur {
+                input_valid_handle.set(validate.emit(value).is_valid());
+            }
+        })
+    };
+
+    let on_empty_state_click = {
+        let input_ref = props.input_ref.clone();
+        Callback::from(move |_| {
+            if let Some(input) = input_ref.cast::<HtmlElement>() {
+                let _ = input.focus();
+            }
+        })
+    };
+
+    let on_clear_click = {
+        let input_ref = props.input_ref.clone();
+        let input_handle = props.input_handle.clone();
+        let input_valid_handle = props.input_valid_handle.clone();
+        let validate = validate.clone();
+        let on_clear = props.on_clear.clone();
+        Callback::from(move |_: MouseEvent| {
+            input_valid_handle.set(validate.emit(String::new()).is_valid());
+            input_handle.set(String::new());
+            on_clear.emit(());
+            // Returns the focus to the field the button disappears from
+            if let Some(input) = input_ref.cast::<HtmlElement>() {
+                let _ = input.focus();
             }
         })
     };
@@ -297,73 +1730,488 @@ pub fn custom_input(props: &Props) -> Html {
                 password_type_handle.set("text")
             }
             eye_active_handle.set(!eye_active);
+            password_toggled_handle.set(true);
         })
     };
 
+    let error_visible = props.show_error.unwrap_or(!input_valid);
+    let inline_error = error_visible && props.show_error_text && props.error_sink.is_none();
+    let aria_errormessage = (inline_error && !props.aria_describedby.is_empty())
+        .then_some(props.aria_describedby);
+
+    let helper_id = format!("{}-helper", if props.input_id.is_empty() { props.name } else { props.input_id });
+    let helper_visible =
+        !props.helper_text.is_empty() && (props.helper_error_mode != "replace" || !inline_error);
+    // Only references the feedback elements that are currently rendered: the error is either inline, or
+    // displayed by the consumer when `show_error_text` is off, e.g. by a `CustomInputGroup`, but never when
+    // it's handed to an `error_sink`.
+    let error_described = error_visible && props.error_sink.is_none();
+    let aria_describedby = [
+        helper_visible.then(|| helper_id.clone()),
+        (error_described && !props.aria_describedby.is_empty())
+            .then(|| props.aria_describedby.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(" ");
+    let aria_describedby = (!aria_describedby.is_empty()).then_some(aria_describedby);
+
+    let display_value = match &props.display_format {
+        Some(display_format) => display_format.emit((*props.input_handle).clone()),
+        None => (*props.input_handle).clone(),
+    };
+
+    let tabindex = props.hidden.then_some("-1");
+    let input_tabindex = match tabindex {
+        Some(hidden) => Some(hidden.to_string()),
+        None => props.tabindex.map(|tabindex| tabindex.to_string()),
+    };
+
+    // Mirrors the numeric range for custom-styled number and range inputs, which screen readers can't
+    // always infer from the native attributes.
+    let (aria_valuemin, aria_valuemax, aria_valuenow) = if matches!(input_type, "number" | "range") {
+        (
+            props.min.map(|min| min.to_string()),
+            props.max.map(|max| max.to_string()),
+            props.input_handle.trim().parse::<f64>().ok().map(|value| value.to_string()),
+        )
+    } else {
+        (None, None, None)
+    };
+
+    let autocomplete = if props.disable_autofill {
+        Some(if input_type == "password" { "new-password" } else { "off" })
+    } else {
+        (!props.autocomplete.is_empty()).then_some(props.autocomplete)
+    };
+    let autofill_readonly = props.disable_autofill && !focused;
+
+    let input_mode = if !props.input_mode.is_empty() {
+        Some(props.input_mode)
+    } else if input_type == "number" {
+        let has_decimals = [props.step, props.min]
+            .into_iter()
+            .flatten()
+            .any(|value| decimal_places(value) > 0);
+        Some(if has_decimals { "decimal" } else { "numeric" })
+    } else {
+        None
+    };
+
+    let error_message = if !props.step_mismatch_message.is_empty()
+        && validation.errors.contains(&ValidationError::StepMismatch)
+    {
+        props.step_mismatch_message
+    } else if !props.word_count_message.is_empty()
+        && validation
+            .errors
+            .iter()
+            .any(|error| matches!(error, ValidationError::TooFewWords | ValidationError::TooManyWords))
+    {
+        props.word_count_message
+    } else {
+        default_error_message
+    };
+
+    let mut error_messages: Vec<&'static str> = Vec::new();
+    for error in &validation.errors {
+        let message = match error {
+            ValidationError::Custom if !props.error_message.is_empty() => props.error_message,
+            ValidationError::PatternMismatch if !pattern_message.is_empty() => pattern_message,
+            ValidationError::StepMismatch if !props.step_mismatch_message.is_empty() => {
+                props.step_mismatch_message
+            }
+            ValidationError::TooFewWords | ValidationError::TooManyWords
+                if !props.word_count_message.is_empty() =>
+            {
+                props.word_count_message
+            }
+            error => error.message(),
+        };
+        if !error_messages.contains(&message) {
+            error_messages.push(message);
+        }
+    }
+    if error_messages.is_empty() {
+        error_messages.push(error_message);
+    }
+
+    {
+        let error_sink = props.error_sink.clone();
+        let name = props.name;
+        use_effect_with((error_visible, error_message), move |(error_visible, error_message)| {
+            if let Some(error_sink) = error_sink {
+                let message = if *error_visible { *error_message } else { "" };
+                error_sink.emit((name.to_string(), message.to_string()));
+            }
+        });
+    }
+
+    {
+        let input_ref = props.input_ref.clone();
+        use_effect_with((props.hidden, focused), move |(hidden, focused)| {
+            // Hiding a focused field would otherwise leave the focus trapped in it
+            if *hidden && *focused {
+                if let Some(input) = input_ref.cast::<HtmlElement>() {
+                    let _ = input.blur();
+                }
+            }
+        });
+    }
+
+    if let Some(render) = &props.render {
+        return render.emit(FieldRenderArgs {
+            name: props.name,
+            input_id: props.input_id,
+            value: (*props.input_handle).clone(),
+            valid: input_valid,
+            error_visible,
+            error_message: default_error_message,
+            errors: validation.errors.clone(),
+            input_ref: props.input_ref.clone(),
+            oninput,
+            onchange,
+            onfocus: on_focus,
+            onblur: on_blur,
+            onkeydown: on_keydown,
+        });
+    }
+
+    #[cfg(feature = "phone")]
+    let tel_value = {
+        let e164 = if props.tel_include_plus || props.input_handle.is_empty() {
+            (*props.input_handle).clone()
+        } else {
+            format!("+{}", *props.input_handle)
+        };
+        // Stored in E.164, displayed in the format of the selected country
+        match country {
+            Some(country) => display_phone(&country, &e164),
+            None => e164,
+        }
+    };
+
+    // The tel value displays the dialing code followed by the formatted national digits, so its length
+    // bounds follow the format of the selected country, with the extra digits allowed up to the E.164
+    // maximum.
+    #[cfg(feature = "phone")]
+    let (tel_min_length, tel_max_length) = match country {
+        Some(country) => {
+            let extra_digits =
+                (E164_MAX_DIGITS + 1).saturating_sub(country.code.len() + national_length(&country));
+            (
+                display_length(&country).to_string(),
+                (display_length(&country) + extra_digits).to_string(),
+            )
+        }
+        None => ("9".to_string(), "14".to_string()),
+    };
+
+    // Only the options scrolled into view and a buffer around them are rendered, between spacers keeping
+    // the scroll height of the whole list.
+    #[cfg(feature = "phone")]
+    let country_window = {
+        let first_visible = (country_scroll / COUNTRY_OPTION_HEIGHT) as usize;
+        let start = first_visible.saturating_sub(COUNTRY_LIST_BUFFER).min(country_matches.len());
+        let end = (first_visible + COUNTRY_LIST_ROWS + COUNTRY_LIST_BUFFER).min(country_matches.len());
+        start..end
+    };
+
+    #[cfg(feature = "phone")]
+    let flag_image = |option: Country, flag_src: &Callback<Country, AttrValue>| {
+        let loading = !loaded_flags.borrow().contains(option.name);
+        let onload = {
+            let loaded_flags = loaded_flags.clone();
+            let flags_loaded = flags_loaded.clone();
+            Callback::from(move |_: Event| {
+                loaded_flags.borrow_mut().insert(option.name);
+                flags_loaded.force_update();
+            })
+        };
+        html! {
+            <img
+                class={classes!("country-flag", loading.then_some("loading"))}
+                src={flag_src.emit(option)}
+                alt=""
+                loading="lazy"
+                {onload}
+            />
+        }
+    };
+
+    let eye_icon_html = if eye_active {
+        props.eye_active_html.clone()
+    } else {
+        props.eye_disabled_html.clone()
+    };
+
     let input_tag = match (*input_type).into() {
+        _ if props.plaintext => html! {
+            <>
+                <div class={props.form_input_plaintext_class} id={props.input_id} aria-label={props.aria_label}>
+                    {
+                        match props.mask_display {
+                            Some(visible) => mask_value(&display_value, visible),
+                            None => display_value.clone(),
+                        }
+                    }
+                </div>
+                if !(props.mask_display_only && props.mask_display.is_some()) {
+                    <input type="hidden" name={props.name} value={(*props.input_handle).clone()} />
+                }
+            </>
+        },
         "password" => html! {
             <>
                 <input
                     type={password_type}
                     class={props.form_input_input_class}
+                    style={(!props.input_style.is_empty()).then(|| props.input_style.clone())}
+                    tabindex={input_tabindex.clone()}
+                    disabled={props.disabled}
+                    autocomplete={autocomplete}
+                    readonly={autofill_readonly}
                     id={props.input_id}
                     name={props.name}
-                    value={(*props.input_handle).clone()}
+                    value={display_value.clone()}
                     ref={props.input_ref.clone()}
                     placeholder={props.input_placeholder}
+                    title={title}
+                    minlength={props.min_length.map(|min_length| min_length.to_string())}
+                    pattern={(!props.pattern.is_empty()).then_some(props.pattern)}
+                    maxlength={props.max_length.filter(|_| props.enforce_max_length && !props.count_graphemes).map(|max_length| max_length.to_string())}
                     aria-label={props.aria_label}
                     aria-required={aria_required}
                     aria-invalid={aria_invalid}
-                    aria-describedby={props.aria_describedby}
-                    oninput={onchange}
-                    required={props.required}
-                />
-                <span
-                    class={format!("toggle-button {}", if eye_active { eye_icon_active } else { eye_icon_disabled })}
-                    onclick={on_toggle_password}
+                    aria-errormessage={aria_errormessage}
+                    aria-describedby={aria_describedby.clone()}
+                    oninput={oninput}
+                    onchange={onchange}
+                    onkeydown={on_keydown}
+                    onfocus={on_focus}
+                    onblur={on_blur}
+                    required={required}
                 />
+                if let Some(eye_icon_html) = eye_icon_html {
+                    <button
+                        type="button"
+                        class="toggle-button"
+                        aria-label="Show password"
+                        aria-pressed={eye_active.to_string()}
+                        tabindex={tabindex}
+                        disabled={props.disabled}
+                        onclick={on_toggle_password}
+                    >
+                        { eye_icon_html }
+                    </button>
+                } else {
+                    <button
+                        type="button"
+                        class={format!("toggle-button {}", if eye_active { eye_icon_active } else { eye_icon_disabled })}
+                        aria-label="Show password"
+                        aria-pressed={eye_active.to_string()}
+                        tabindex={tabindex}
+                        disabled={props.disabled}
+                        onclick={on_toggle_password}
+                    />
+                }
+                <span role="status" aria-live="polite" style={VISUALLY_HIDDEN_STYLE}>
+                    if password_toggled {
+                        { if eye_active { props.password_shown_message } else { props.password_hidden_message } }
+                    }
+                </span>
             </>
         },
+        "number" => html! {
+            <input
+                type="number"
+                inputmode={input_mode}
+                class={props.form_input_input_class}
+                style={(!props.input_style.is_empty()).then(|| props.input_style.clone())}
+                tabindex={input_tabindex.clone()}
+                disabled={props.disabled}
+                autocomplete={autocomplete}
+                readonly={autofill_readonly}
+                id={props.input_id}
+                name={props.name}
+                value={(*props.input_handle).clone()}
+                ref={props.input_ref.clone()}
+                placeholder={props.input_placeholder}
+                title={title}
+                min={props.min.map(|min| min.to_string())}
+                max={props.max.map(|max| max.to_string())}
+                step={props.step.map(|step| step.to_string())}
+                aria-label={props.aria_label}
+                aria-required={aria_required}
+                aria-invalid={aria_invalid}
+                aria-errormessage={aria_errormessage}
+                aria-describedby={aria_describedby.clone()}
+                aria-valuemin={aria_valuemin.clone()}
+                aria-valuemax={aria_valuemax.clone()}
+                aria-valuenow={aria_valuenow.clone()}
+                oninput={oninput}
+                onchange={onchange}
+                onfocus={on_focus}
+                onblur={on_blur}
+                onkeydown={on_keydown}
+                onwheel={on_number_wheel}
+                required={required}
+            />
+        },
         "textarea" => html! {
             <textarea
                 class={props.form_input_input_class}
+                style={(!props.input_style.is_empty()).then(|| props.input_style.clone())}
+                tabindex={input_tabindex.clone()}
+                disabled={props.disabled}
+                autocomplete={autocomplete}
+                readonly={autofill_readonly}
                 id={props.input_id}
                 name={props.name}
-                value={(*props.input_handle).clone()}
+                value={display_value.clone()}
                 ref={props.input_ref.clone()}
                 placeholder={props.input_placeholder}
+                title={title}
+                minlength={props.min_length.map(|min_length| min_length.to_string())}
+                maxlength={props.max_length.filter(|_| props.enforce_max_length && !props.count_graphemes).map(|max_length| max_length.to_string())}
                 aria-label={props.aria_label}
                 aria-required={aria_required}
                 aria-invalid={aria_invalid}
-                aria-describedby={props.aria_describedby}
-                oninput={onchange}
-                required={props.required}
+                aria-errormessage={aria_errormessage}
+                aria-describedby={aria_describedby.clone()}
+                oninput={oninput}
+                onchange={onchange}
+                onkeydown={on_keydown}
+                onfocus={on_focus}
+                onblur={on_blur}
+                required={required}
             />
         },
+        #[cfg(feature = "phone")]
         "tel" => html! {
             <>
-                <select ref={input_country_ref} onchange={on_select_change}>
-                    { for COUNTRY_CODES.iter().map(|(code, emoji, _, name, _, _)| {
-                            let selected = *code == country;
-                            html! {
-                                <option value={*code} selected={selected}>{ format!("{} {} {}", emoji, name, code) }</option>
-                            }
-                        }) }
-                </select>
+                if props.searchable_countries {
+                    <div class="country-combobox">
+                        if let (Some(country), Some(flag_src)) = (country, &props.flag_src) {
+                            { flag_image(country, flag_src) }
+                        } else {
+                            <span class="country-flag" aria-hidden="true">
+                                { country.map(|country| country.flag).unwrap_or_default() }
+                            </span>
+                        }
+                        <input
+                            type="text"
+                            role="combobox"
+                            aria-label="Country"
+                            aria-autocomplete="list"
+                            aria-expanded={country_list_open.to_string()}
+                            aria-controls={country_list_id.clone()}
+                            aria-activedescendant={(country_list_open && !country_matches.is_empty())
+                                .then(|| format!("{}-{}", country_list_id, country_active))}
+                            autocomplete="off"
+                            tabindex={tabindex}
+                            disabled={props.disabled}
+                            value={(*country_query_handle).clone()}
+                            placeholder={country.map_or("Search country", |country| country.name)}
+                            oninput={on_country_query_input}
+                            onkeydown={on_country_query_keydown}
+                            onfocus={on_country_query_focus}
+                            onblur={on_country_query_blur}
+                        />
+                        if country_list_open {
+                            <ul
+                                class="country-listbox"
+                                role="listbox"
+                                id={country_list_id.clone()}
+                                aria-label="Countries"
+                                style={format!("max-height: {}px; overflow-y: auto;", COUNTRY_LIST_ROWS as f64 * COUNTRY_OPTION_HEIGHT)}
+                                ref={country_list_ref}
+                                onscroll={on_country_list_scroll}
+                            >
+                                <li role="presentation" style={format!("height: {}px;", country_window.start as f64 * COUNTRY_OPTION_HEIGHT)} />
+                                { for country_matches[country_window.clone()].iter().zip(country_window.clone()).map(|(option, index)| {
+                                    let option = **option;
+                                    let selected = country.is_some_and(|country| country == option);
+                                    // Selecting on mousedown keeps the focus, which would otherwise close the list first
+                                    let onmousedown = {
+                                        let pick_country = pick_country.clone();
+                                        Callback::from(move |event: MouseEvent| {
+                                            event.prevent_default();
+                                            pick_country.emit(option);
+                                        })
+                                    };
+                                    html! {
+                                        <li
+                                            id={format!("{}-{}", country_list_id, index)}
+                                            class={if index == country_active { "active" } else { "" }}
+                                            role="option"
+                                            aria-selected={selected.to_string()}
+                                            aria-setsize={country_matches.len().to_string()}
+                                            aria-posinset={(index + 1).to_string()}
+                                            style={format!("height: {}px;", COUNTRY_OPTION_HEIGHT)}
+                                            {onmousedown}
+                                        >
+                                            if let Some(flag_src) = &props.flag_src {
+                                                { flag_image(option, flag_src) }
+                                                { format!(" {} {}", option.name, option.code) }
+                                            } else {
+                                                { format!("{} {} {}", option.flag, option.name, option.code) }
+                                            }
+                                        </li>
+                                    }
+                                }) }
+                                <li
+                                    role="presentation"
+                                    style={format!("height: {}px;", (country_matches.len() - country_window.end) as f64 * COUNTRY_OPTION_HEIGHT)}
+                                />
+                            </ul>
+                        }
+                    </div>
+                } else {
+                    <select
+                        ref={input_country_ref}
+                        tabindex={tabindex}
+                        disabled={props.disabled}
+                        onchange={on_select_change}
+                        onfocus={request_country_options.reform(|_: FocusEvent| ())}
+                        onmousedown={request_country_options.reform(|_: MouseEvent| ())}
+                    >
+                        { for country_options.iter().map(|Country { code, flag, name, .. }| {
+                                let selected = country.is_some_and(|country| country.name == *name);
+                                html! {
+                                    <option value={*name} selected={selected}>{ format!("{} {} {}", flag, name, code) }</option>
+                                }
+                            }) }
+                    </select>
+                }
                 <input
                     type="tel"
                     id="telNo"
                     name="telNo"
                     size="20"
-                    minlength="9"
-                    value={(*props.input_handle).clone()}
-                    maxlength="14"
+                    minlength={tel_min_length}
+                    value={tel_value}
+                    maxlength={tel_max_length}
                     class={props.form_input_input_class}
+                    style={(!props.input_style.is_empty()).then(|| props.input_style.clone())}
+                    tabindex={input_tabindex.clone()}
+                    disabled={props.disabled}
+                    autocomplete={autocomplete}
+                    readonly={autofill_readonly}
                     placeholder={props.input_placeholder}
+                    title={title}
                     aria-label={props.aria_label}
                     aria-required={aria_required}
                     aria-invalid={aria_invalid}
+                    aria-errormessage={aria_errormessage}
+                    aria-describedby={aria_describedby.clone()}
                     oninput={on_phone_number_input}
+                    onchange={props.on_raw_change.clone()}
+                    onpaste={on_phone_paste}
+                    onkeydown={on_keydown}
+                    onfocus={on_focus}
+                    onblur={on_blur}
                     ref={props.input_ref.clone()}
                 />
             </>
@@ -371,34 +2219,361 @@ pub fn custom_input(props: &Props) -> Html {
         _ => html! {
             <input
                 type={input_type}
+                inputmode={input_mode}
                 class={props.form_input_input_class}
+                style={(!props.input_style.is_empty()).then(|| props.input_style.clone())}
+                tabindex={input_tabindex.clone()}
+                disabled={props.disabled}
+                autocomplete={autocomplete}
+                readonly={autofill_readonly}
                 id={props.input_id}
-                value={(*props.input_handle).clone()}
+                // The value of a file input can only be cleared, not set
+                value={(input_type != "file").then_some(display_value)}
                 name={props.name}
                 ref={props.input_ref.clone()}
                 placeholder={props.input_placeholder}
+                title={title}
+                minlength={props.min_length.map(|min_length| min_length.to_string())}
+                pattern={(!props.pattern.is_empty()).then_some(props.pattern)}
+                maxlength={props.max_length.filter(|_| props.enforce_max_length && !props.count_graphemes).map(|max_length| max_length.to_string())}
+                min={props.min.filter(|_| input_type == "range").map(|min| min.to_string())}
+                max={props.max.filter(|_| input_type == "range").map(|max| max.to_string())}
+                step={props.step.filter(|_| input_type == "range").map(|step| step.to_string())}
                 aria-label={props.aria_label}
                 aria-required={aria_required}
                 aria-invalid={aria_invalid}
-                aria-describedby={props.aria_describedby}
-                oninput={onchange}
-                required={props.required}
+                aria-errormessage={aria_errormessage}
+                aria-describedby={aria_describedby.clone()}
+                aria-valuemin={aria_valuemin}
+                aria-valuemax={aria_valuemax}
+                aria-valuenow={aria_valuenow}
+                oninput={oninput}
+                onchange={onchange}
+                onkeydown={on_keydown}
+                onfocus={on_focus}
+                onblur={on_blur}
+                required={required}
             />
         },
     };
 
-    html! {
-        <div class={props.form_input_class}>
-            <label class={props.form_input_label_class} for={props.input_id}>{ props.label }</label>
-            <div class={props.form_input_field_class}>
-                { input_tag }
-                <span class={props.icon_class} />
+    let counter = match props.max_length {
+        Some(max_length) if props.show_counter => {
+            let char_count = text_length(&props.input_handle, props.count_graphemes);
+            let usage = if max_length == 0 {
+                1.0
+            } else {
+                char_count as f64 / max_length as f64
+            };
+            let reached = |threshold: Option<f64>| threshold.is_some_and(|threshold| usage >= threshold);
+            let counter_class = classes!(
+                props.form_input_counter_class,
+                if reached(props.counter_danger_threshold) {
+                    Some(props.form_input_counter_danger_class)
+                } else {
+                    reached(props.counter_warn_threshold).then_some(props.form_input_counter_warn_class)
+                },
+            );
+            if props.counter_style == "ring" {
+                let circumference = 2.0 * std::f64::consts::PI * COUNTER_RING_RADIUS;
+                let ratio = usage.min(1.0);
+                html! {
+                    <div class={counter_class}>
+                        <svg
+                            width="24"
+                            height="24"
+                            viewBox="0 0 24 24"
+                            role="img"
+                            aria-label={format!("{} of {} characters used", char_count, max_length)}
+                        >
+                            <circle cx="12" cy="12" r={COUNTER_RING_RADIUS.to_string()} fill="none" stroke="currentColor" stroke-opacity="0.2" stroke-width="2" />
+                            <circle
+                                cx="12"
+                                cy="12"
+                                r={COUNTER_RING_RADIUS.to_string()}
+                                fill="none"
+                                stroke="currentColor"
+                                stroke-width="2"
+                                stroke-dasharray={circumference.to_string()}
+                                stroke-dashoffset={(circumference * (1.0 - ratio)).to_string()}
+                                transform="rotate(-90 12 12)"
+                            />
+                        </svg>
+                    </div>
+                }
+            } else {
+                html! {
+                    <div class={counter_class}>
+                        { format!("{}/{}", char_count, max_length) }
+                    </div>
+                }
+            }
+        }
+        _ => html! {},
+    };
+
+    let word_counter = if props.show_word_count && input_type == "textarea" {
+        let words = word_count(&props.input_handle);
+        html! {
+            <div class={props.form_input_counter_class}>
+                {
+                    match props.max_words {
+                        Some(max_words) => format!("{}/{} words", words, max_words),
+                        None => format!("{} words", words),
+                    }
+                }
             </div>
-            if !input_valid {
+        }
+    } else {
+        html! {}
+    };
+
+    let strength_shown = input_type == "password" && !props.input_handle.is_empty();
+    let strength_score = if strength_shown {
+        strength_function.emit((*props.input_handle).clone())
+    } else {
+        0
+    };
+    // Each label covers an equal share of the scores, from 0 to MAX_STRENGTH
+    let strength_stage = (usize::from(strength_score.min(MAX_STRENGTH)) * props.strength_labels.len())
+        / (usize::from(MAX_STRENGTH) + 1);
+    let strength_label = props.strength_labels.get(strength_stage).copied();
+
+    let strength_meter = if props.show_strength && strength_shown {
+        html! {
+            <meter
+                class={props.form_input_strength_class}
+                min="0"
+                max={MAX_STRENGTH.to_string()}
+                low="2"
+                high="3"
+                optimum={MAX_STRENGTH.to_string()}
+                value={strength_score.to_string()}
+                aria-label="Password strength"
+                aria-valuetext={strength_label}
+            />
+        }
+    } else {
+        html! {}
+    };
+
+    let strength_stages = if !props.strength_labels.is_empty() && strength_shown {
+        html! {
+            <ol class={props.form_input_strength_labels_class} aria-label="Password strength">
+                { for props.strength_labels.iter().enumerate().map(|(stage, label)| html! {
+                    <li
+                        class={(stage <= strength_stage).then_some("reached")}
+                        aria-current={(stage == strength_stage).then_some("step")}
+                    >
+                        { *label }
+                    </li>
+                }) }
+            </ol>
+        }
+    } else {
+        html! {}
+    };
+
+    let range_meter = match (props.min, props.max) {
+        (Some(min), Some(max)) if props.show_meter && input_type == "number" => {
+            let value = props.input_handle.trim().parse::<f64>().unwrap_or(min).clamp(min, max.max(min));
+            html! {
+                <meter
+                    class={props.form_input_meter_class}
+                    min={min.to_string()}
+                    max={max.to_string()}
+                    value={value.to_string()}
+                    aria-label={format!("{} within {} to {}", props.label, min, max)}
+                />
+            }
+        }
+        _ => html! {},
+    };
+
+    let validation_icon = if props.validation_icon && error_visible {
+        html! {
+            <span
+                class={format!("{} {}", props.validation_icon_class, props.error_icon)}
+                role="img"
+                tabindex={tabindex.unwrap_or("0")}
+                title={error_message}
+                aria-label={format!("Invalid input: {}", error_message)}
+            />
+        }
+    } else if props.validation_icon && input_valid && touched {
+        html! {
+            <span
+                class={format!("{} {}", props.validation_icon_class, props.success_icon)}
+                role="img"
+                aria-label="Valid input"
+            />
+        }
+    } else {
+        html! {}
+    };
+
+    let label_after_input = props
+        .label_after_input
+        .unwrap_or(matches!(input_type, "checkbox" | "radio"));
+    let label = html! {
+        <label class={props.form_input_label_class} for={props.input_id}>{ props.label }</label>
+    };
+
+    let field = html! {
+        <>
+            if let Some(prefix) = props.prefix.clone() {
+                <span class={props.form_input_prefix_class}>{ prefix }</span>
+            }
+            { input_tag }
+            if let Some(suffix) = props.suffix.clone() {
+                <span class={props.form_input_suffix_class}>{ suffix }</span>
+            }
+            if props.clearable && !props.plaintext && !props.input_handle.is_empty() {
+                <button
+                    type="button"
+                    class={props.form_input_clear_class}
+                    aria-label="Clear"
+                    tabindex={tabindex}
+                    disabled={props.disabled}
+                    onclick={on_clear_click}
+                >
+                    { "\u{00D7}" }
+                </button>
+            }
+            if props.input_handle.is_empty() && !focused {
+                if let Some(empty_state) = props.empty_state.clone() {
+                    <div
+                        class={props.form_input_empty_state_class}
+                        aria-hidden="true"
+                        onclick={on_empty_state_click}
+                    >
+                        { empty_state }
+                    </div>
+                }
+            }
+            <span
+                class={props.icon_class}
+                style={if props.icon_clickable { "" } else { "pointer-events: none;" }}
+                aria-hidden="true"
+            />
+            { validation_icon }
+        </>
+    };
+
+    let mut container_style = String::new();
+    if !props.error_color.is_empty() {
+        container_style.push_str(&format!("--input-error-color: {};", props.error_color));
+    }
+    if !props.success_color.is_empty() {
+        container_style.push_str(&format!("--input-success-color: {};", props.success_color));
+    }
+    container_style.push_str(&props.style);
+
+    html! {
+        <div
+            class={classes!(
+                props.form_input_class,
+                focused.then_some(props.focus_class),
+                props.hidden.then_some(props.form_input_hidden_class),
+            )}
+            style={container_style}
+            aria-hidden={props.hidden.then_some("true")}
+            inert={(props.disabled && props.inert).then_some("")}
+            aria-busy={props.validation_pending.then_some("true")}
+            role={(!props.group_label.is_empty()).then_some("group")}
+            aria-label={(!props.group_label.is_empty()).then_some(props.group_label)}
+        >
+            if !label_after_input {
+                { label.clone() }
+            }
+            if props.wrap_field {
+                <div class={props.form_input_field_class}>{ field }</div>
+            } else {
+                { field }
+            }
+            if label_after_input {
+                { label }
+            }
+            { counter }
+            { word_counter }
+            { strength_meter }
+            { strength_stages }
+            { range_meter }
+            if let Some(output) = props.output.clone() {
+                <output class={props.form_input_output_class} for={props.input_id} name={format!("{}-output", props.name)}>
+                    { output }
+                </output>
+            }
+            if helper_visible {
+                <div class={props.form_input_helper_class} id={helper_id}>
+                    { props.helper_text }
+                </div>
+            }
+            if let Some(attempts) = props.attempts_remaining {
+                <div class={props.form_input_attempts_class} role="status" aria-live="polite">
+                    { format!("{} {} remaining", attempts, if attempts == 1 { "attempt" } else { "attempts" }) }
+                </div>
+            }
+            if inline_error {
                 <div class={props.form_input_error_class} id={props.aria_describedby}>
-                    { &props.error_message }
+                    if props.show_all_errors {
+                        <ul>
+                            { for error_messages.iter().map(|message| html! { <li>{ *message }</li> }) }
+                        </ul>
+                    } else {
+                        { error_message }
+                    }
+                </div>
+            }
+            // Rendered while the check is pending so that the outcome is announced when it's inserted
+            if props.validation_pending || async_settled {
+                <div role="status" aria-live="polite" style={VISUALLY_HIDDEN_STYLE}>
+                    if async_settled {
+                        { if error_visible { error_message } else { props.async_success_message } }
+                    }
                 </div>
             }
         </div>
     }
 }
+
+/// Reads the current value of the input or textarea referenced by `node_ref`.
+fn read_value(node_ref: &NodeRef) -> Option<String> {
+    node_ref
+        .cast::<HtmlInputElement>()
+        .map(|input| input.value())
+        .or_else(|| node_ref.cast::<HtmlTextAreaElement>().map(|textarea| textarea.value()))
+}
+
+/// Returns the tel `value` to store in the input handle, without its leading `+` unless `include_plus` is set.
+#[cfg(feature = "phone")]
+fn stored_tel_value(value: &str, include_plus: bool) -> String {
+    if include_plus {
+        value.to_string()
+    } else {
+        value.trim_start_matches('+').to_string()
+    }
+}
+
+/// Returns the scroll offset of the searchable country list keeping the option at `index` in view, starting
+/// from the current `scroll_top`.
+#[cfg(feature = "phone")]
+fn country_scroll_to(scroll_top: f64, index: usize) -> f64 {
+    let top = index as f64 * COUNTRY_OPTION_HEIGHT;
+    let bottom = top + COUNTRY_OPTION_HEIGHT;
+    let height = COUNTRY_LIST_ROWS as f64 * COUNTRY_OPTION_HEIGHT;
+    if top < scroll_top {
+        top
+    } else if bottom > scroll_top + height {
+        bottom - height
+    } else {
+        scroll_top
+    }
+}
+
+/// Returns the number of decimal places in `step`, used to format stepped number values.
+fn decimal_places(step: f64) -> usize {
+    step.to_string()
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
@@ -0,0 +1,232 @@
+use crate::countries::Country;
+#[cfg(feature = "phone")]
+use crate::countries::COUNTRY_CODES;
+
+/// The maximum number of digits in an E.164 phone number, including the country code.
+pub const E164_MAX_DIGITS: usize = 15;
+
+/// A parsed phone number, reported by the tel input through `on_phone_change`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhoneValue {
+    /// The number in E.164 format, e.g. "+33612345678".
+    pub e164: String,
+    /// The national part of the number formatted for the country, e.g. "61 23 45 678".
+    pub national: String,
+    /// The country matching the dialing code of the number.
+    pub country: Country,
+    /// Whether the number has a plausible length for the country.
+    pub is_valid: bool,
+}
+
+/// Returns the number of national digits expected by the format of `country`.
+pub fn national_length(country: &Country) -> usize {
+    country.format.chars().filter(|c| *c == '.').count()
+}
+
+/// The countries preferred over the others sharing their dialing code, e.g. the United States for `+1`.
+/// For other shared codes, the first listed country is used.
+#[cfg(feature = "phone")]
+const PRIMARY_COUNTRIES: [&str; 4] = ["United States", "United Kingdom", "Russia", "R\u{E9}union"];
+
+/// match_country
+/// Finds the country whose dialing code is the longest prefix of `number`.
+///
+/// When several countries share the matched code, `preferred` wins if it is one of them, e.g. the
+/// currently selected country; otherwise the primary country for that code is used, such as the United
+/// States for `+1` or the United Kingdom for `+44`.
+///
+/// # Arguments
+/// * `number` - A phone number starting with `+` and its dialing code.
+/// * `preferred` - The currently selected country, if any.
+///
+/// # Returns
+/// (Option<&Country>): The matching country, if any.
+///
+/// # Examples
+/// ```
+/// use input_yew::countries::COUNTRY_CODES;
+/// use input_yew::phone::match_country;
+///
+/// assert_eq!(match_country("+12025550123", None).unwrap().name, "United States");
+/// let canada = COUNTRY_CODES.iter().find(|country| country.name == "Canada");
+/// assert_eq!(match_country("+14165550123", canada).unwrap().name, "Canada");
+/// assert_eq!(match_country("+12845550123", canada).unwrap().name, "British Virgin Islands");
+/// ```
+#[cfg(feature = "phone")]
+pub fn match_country(number: &str, preferred: Option<&Country>) -> Option<&'static Country> {
+    match_country_in(&COUNTRY_CODES, number, preferred)
+}
+
+/// match_country_in
+/// Finds the country of a custom list whose dialing code is the longest prefix of `number`, see
+/// `match_country`.
+///
+/// # Arguments
+/// * `countries` - The countries to match against.
+/// * `number` - A phone number starting with `+` and its dialing code.
+/// * `preferred` - The currently selected country, if any.
+///
+/// # Returns
+/// (Option<&Country>): The matching country, if any.
+#[cfg(feature = "phone")]
+pub fn match_country_in(
+    countries: &'static [Country],
+    number: &str,
+    preferred: Option<&Country>,
+) -> Option<&'static Country> {
+    let code = countries
+        .iter()
+        .map(|country| country.code)
+        .filter(|code| number.starts_with(code))
+        .max_by_key(|code| code.len())?;
+    let mut candidates = countries.iter().filter(|country| country.code == code);
+    preferred
+        .and_then(|preferred| candidates.clone().find(|country| *country == preferred))
+        .or_else(|| {
+            candidates
+                .clone()
+                .find(|country| PRIMARY_COUNTRIES.contains(&country.name))
+        })
+        .or_else(|| candidates.next())
+}
+
+/// Returns the format of the national part of the numbers of `country`, e.g. ".. .. .. .." for France.
+fn national_pattern(country: &Country) -> &'static str {
+    country
+        .format
+        .strip_prefix(country.code)
+        .unwrap_or(country.format)
+        .trim_start()
+}
+
+/// Returns the E.164 number `e164` as displayed by the tel input: the dialing code of `country` followed by
+/// the national digits in the format of the country, or `e164` as is when it has another dialing code.
+#[cfg(feature = "phone")]
+pub(crate) fn display_phone(country: &Country, e164: &str) -> String {
+    match e164.strip_prefix(country.code) {
+        Some(national) if !national.is_empty() => {
+            format!("{} {}", country.code, format_national(country, national))
+        }
+        _ => e164.to_string(),
+    }
+}
+
+/// Returns the length of a complete number of `country` as displayed by the tel input, see `display_phone`.
+#[cfg(feature = "phone")]
+pub(crate) fn display_length(country: &Country) -> usize {
+    country.code.len() + 1 + national_pattern(country).chars().count()
+}
+
+/// format_national
+/// Formats national digits using the format of `country`, appending any digits beyond the format.
+///
+/// # Examples
+/// ```
+/// use input_yew::countries::Country;
+/// use input_yew::phone::format_national;
+///
+/// let france = Country {
+///     code: "+33",
+///     flag: "\u{1F1EB}\u{1F1F7}",
+///     format: "+33 .. .. .. ..",
+///     name: "France",
+///     continent: "Europe",
+///     region: "Western Europe",
+/// };
+/// assert_eq!(format_national(&france, "61234567"), "61 23 45 67");
+/// assert_eq!(format_national(&france, "6123"), "61 23");
+/// ```
+pub fn format_national(country: &Country, national: &str) -> String {
+    let pattern = national_pattern(country);
+    let mut digits = national.chars().peekable();
+    let mut formatted = String::new();
+    for symbol in pattern.chars() {
+        if digits.peek().is_none() {
+            break;
+        }
+        if symbol == '.' {
+            formatted.extend(digits.next());
+        } else {
+            formatted.push(symbol);
+        }
+    }
+    formatted.extend(digits);
+    formatted
+}
+
+/// parse_phone_number
+/// Parses a phone number such as `+33612345678` into its E.164 and national representations.
+///
+/// A number is considered valid when it has at least as many national digits as the country's format
+/// and no more digits than E.164 allows.
+///
+/// # Arguments
+/// * `number` - The phone number; non-digit characters other than the leading `+` are ignored.
+/// * `preferred` - The currently selected country, if any, see `match_country`.
+///
+/// # Returns
+/// (`Option<PhoneValue>`): The parsed number, or `None` when no dialing code matches.
+///
+/// # Examples
+/// ```
+/// use input_yew::phone::parse_phone_number;
+///
+/// let phone = parse_phone_number("+33 6 12 34 56 78", None).unwrap();
+/// assert_eq!(phone.e164, "+33612345678");
+/// assert_eq!(phone.country.name, "France");
+/// assert!(phone.is_valid);
+/// assert!(!parse_phone_number("+336", None).unwrap().is_valid);
+/// assert_eq!(parse_phone_number("+44 20 7946 0958", None).unwrap().country.name, "United Kingdom");
+/// ```
+#[cfg(feature = "phone")]
+pub fn parse_phone_number(number: &str, preferred: Option<&Country>) -> Option<PhoneValue> {
+    parse_phone_number_in(&COUNTRY_CODES, number, preferred)
+}
+
+/// parse_phone_number_in
+/// Parses a phone number against a custom list of countries, see `parse_phone_number`.
+///
+/// # Arguments
+/// * `countries` - The countries the dialing code is matched against.
+/// * `number` - The phone number; non-digit characters other than the leading `+` are ignored.
+/// * `preferred` - The currently selected country, if any, see `match_country`.
+///
+/// # Returns
+/// (`Option<PhoneValue>`): The parsed number, or `None` when no dialing code matches.
+///
+/// # Examples
+/// ```
+/// use input_yew::countries::Country;
+/// use input_yew::phone::parse_phone_number_in;
+///
+/// static OPERATING_REGIONS: [Country; 1] = [Country {
+///     code: "+882",
+///     flag: "\u{1F310}",
+///     format: "+882 .. ........",
+///     name: "International Networks",
+///     continent: "",
+///     region: "",
+/// }];
+///
+/// let phone = parse_phone_number_in(&OPERATING_REGIONS, "+882 16 12345678", None).unwrap();
+/// assert_eq!(phone.country.name, "International Networks");
+/// assert_eq!(phone.national, "16 12345678");
+/// assert!(parse_phone_number_in(&OPERATING_REGIONS, "+33612345678", None).is_none());
+/// ```
+#[cfg(feature = "phone")]
+pub fn parse_phone_number_in(
+    countries: &'static [Country],
+    number: &str,
+    preferred: Option<&Country>,
+) -> Option<PhoneValue> {
+    let digits: String = number.chars().filter(|c| c.is_ascii_digit()).collect();
+    let e164 = format!("+{}", digits);
+    let country = *match_country_in(countries, &e164, preferred)?;
+    let national = &e164[country.code.len()..];
+    Some(PhoneValue {
+        national: format_national(&country, national),
+        is_valid: national.len() >= national_length(&country) && digits.len() <= E164_MAX_DIGITS,
+        e164,
+        country,
+    })
+}
@@ -0,0 +1,136 @@
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+/// Props for the segmented control.
+#[derive(Properties, PartialEq, Clone)]
+pub struct SegmentedProps {
+    /// The options of the control, as `(value, label)` pairs, e.g. `("daily", "Daily")`.
+    pub options: Vec<(&'static str, &'static str)>,
+
+    /// State handle holding the value of the selected option.
+    pub selected_handle: UseStateHandle<String>,
+
+    /// A label announcing the control to screen readers, e.g. "Frequency".
+    #[prop_or_default]
+    pub aria_label: &'static str,
+
+    /// A callback receiving the value of the newly selected option.
+    #[prop_or_default]
+    pub on_change: Callback<String>,
+
+    /// Whether the control is disabled.
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// The CSS class to be applied to the container.
+    #[prop_or_default]
+    pub form_input_class: &'static str,
+
+    /// The CSS class to be applied to every segment.
+    #[prop_or_default]
+    pub segment_class: &'static str,
+
+    /// The CSS class added to the selected segment.
+    #[prop_or("selected")]
+    pub segment_selected_class: &'static str,
+}
+
+/// custom_segmented
+/// A segmented control, such as "Daily / Weekly / Monthly", rendered as a radio group of buttons.
+///
+/// Only the selected segment is tabbable; the arrow keys move the selection between segments, like a
+/// native radio group.
+///
+/// # Examples
+/// ```
+/// use input_yew::CustomSegmented;
+/// use yew::prelude::*;
+///
+/// #[function_component(Frequency)]
+/// fn frequency() -> Html {
+///     let frequency_handle = use_state(|| "weekly".to_string());
+///
+///     html! {
+///         <CustomSegmented
+///           options={vec![("daily", "Daily"), ("weekly", "Weekly"), ("monthly", "Monthly")]}
+///           selected_handle={frequency_handle}
+///           aria_label={"Frequency"}
+///         />
+///     }
+/// }
+/// ```
+#[function_component(CustomSegmented)]
+pub fn custom_segmented(props: &SegmentedProps) -> Html {
+    let select = {
+        let selected_handle = props.selected_handle.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |value: String| {
+            if *selected_handle != value {
+                selected_handle.set(value.clone());
+                on_change.emit(value);
+            }
+        })
+    };
+
+    let selected_index = props
+        .options
+        .iter()
+        .position(|(value, _)| *value == props.selected_handle.as_str());
+    let last = props.options.len().saturating_sub(1);
+
+    html! {
+        <div class={props.form_input_class} role="radiogroup" aria-label={props.aria_label}>
+            { for props.options.iter().enumerate().map(|(index, (value, label))| {
+                let selected = selected_index == Some(index);
+                // Without a selection, the first segment is the one reachable with Tab
+                let tabbable = selected || (selected_index.is_none() && index == 0);
+                let onclick = {
+                    let select = select.clone();
+                    let value = value.to_string();
+                    Callback::from(move |_: MouseEvent| select.emit(value.clone()))
+                };
+                let onkeydown = {
+                    let select = select.clone();
+                    let options = props.options.clone();
+                    Callback::from(move |event: KeyboardEvent| {
+                        let target = match event.key().as_str() {
+                            "ArrowRight" | "ArrowDown" if index == last => 0,
+                            "ArrowRight" | "ArrowDown" => index + 1,
+                            "ArrowLeft" | "ArrowUp" if index == 0 => last,
+                            "ArrowLeft" | "ArrowUp" => index - 1,
+                            "Home" => 0,
+                            "End" => last,
+                            _ => return,
+                        };
+                        event.prevent_default();
+                        select.emit(options[target].0.to_string());
+                        // Move the focus along with the selection to the target segment
+                        let segment: HtmlElement = event.target_unchecked_into();
+                        if let Some(sibling) = segment
+                            .parent_element()
+                            .and_then(|group| group.children().item(target as u32))
+                            .and_then(|sibling| sibling.dyn_into::<HtmlElement>().ok())
+                        {
+                            let _ = sibling.focus();
+                        }
+                    })
+                };
+                html! {
+                    <button
+                        type="button"
+                        class={classes!(props.segment_class, selected.then_some(props.segment_selected_class))}
+                        role="radio"
+                        aria-checked={selected.to_string()}
+                        tabindex={if tabbable { "0" } else { "-1" }}
+                        disabled={props.disabled}
+                        {onclick}
+                        {onkeydown}
+                    >
+                        { *label }
+                    </button>
+                }
+            }) }
+        </div>
+    }
+}
@@ -0,0 +1,41 @@
+/// The highest score returned by `password_strength`.
+pub const MAX_STRENGTH: u8 = 4;
+
+/// password_strength
+/// Scores a password from `0` (empty) to `MAX_STRENGTH` based on its length and character variety.
+///
+/// One point is awarded for reaching 8 characters, one for reaching 12 characters, one for using at
+/// least three of lowercase, uppercase, digits and symbols, and one for using all four.
+///
+/// # Arguments
+/// * `password` - The password to score.
+///
+/// # Returns
+/// (u8): The strength score of the password.
+///
+/// # Examples
+/// ```
+/// use input_yew::strength::password_strength;
+///
+/// assert_eq!(password_strength("".to_string()), 0);
+/// assert_eq!(password_strength("password".to_string()), 1);
+/// assert_eq!(password_strength("Password1".to_string()), 2);
+/// assert_eq!(password_strength("Password1!xyz".to_string()), 4);
+/// ```
+pub fn password_strength(password: String) -> u8 {
+    let length = password.chars().count();
+    let classes = [
+        password.chars().any(|c| c.is_lowercase()),
+        password.chars().any(|c| c.is_uppercase()),
+        password.chars().any(|c| c.is_numeric()),
+        password.chars().any(|c| !c.is_alphanumeric()),
+    ]
+    .iter()
+    .filter(|present| **present)
+    .count();
+
+    [length >= 8, length >= 12, classes >= 3, classes == 4]
+        .iter()
+        .filter(|met| **met)
+        .count() as u8
+}
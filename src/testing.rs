@@ -0,0 +1,61 @@
+//! Helpers for testing forms built with this crate in the browser, e.g. with `wasm-bindgen-test`.
+//!
+//! Only available with the `testing` feature, which is meant for `[dev-dependencies]`.
+
+use std::time::Duration;
+use web_sys::{Element, Event, EventInit, HtmlInputElement, HtmlTextAreaElement};
+use yew::platform::time::sleep;
+use yew::NodeRef;
+
+/// type_into
+/// Replaces the value of the input or textarea referenced by `node_ref` with `text`, dispatches an
+/// `input` event as if the user typed it, then waits for the resulting re-render.
+///
+/// # Arguments
+/// * `node_ref` - The reference passed as `input_ref` to the field.
+/// * `text` - The text to type.
+///
+/// # Examples
+/// ```ignore
+/// use input_yew::testing::{get_error_text, type_into};
+///
+/// type_into(&email_ref, "jane@").await;
+/// assert_eq!(get_error_text(&container, "error-txt").as_deref(), Some("Enter a valid email"));
+/// ```
+pub async fn type_into(node_ref: &NodeRef, text: &str) {
+    if let Some(input) = node_ref.cast::<HtmlInputElement>() {
+        input.set_value(text);
+    } else if let Some(textarea) = node_ref.cast::<HtmlTextAreaElement>() {
+        textarea.set_value(text);
+    } else {
+        return;
+    }
+    if let Some(element) = node_ref.cast::<Element>() {
+        let init = EventInit::new();
+        init.set_bubbles(true);
+        if let Ok(event) = Event::new_with_event_init_dict("input", &init) {
+            let _ = element.dispatch_event(&event);
+        }
+    }
+    // Yields to the scheduler so that the state updates of the event are rendered
+    sleep(Duration::ZERO).await;
+}
+
+/// get_error_text
+/// Reads the error message rendered inside `container`.
+///
+/// # Arguments
+/// * `container` - An element containing the field, e.g. the root the app is rendered into.
+/// * `error_class` - The `form_input_error_class` of the field.
+///
+/// # Returns
+/// (`Option<String>`): The trimmed text of the first error element, or `None` when no error is displayed.
+pub fn get_error_text(container: &Element, error_class: &str) -> Option<String> {
+    let selector = format!(".{}", error_class.split_whitespace().collect::<Vec<_>>().join("."));
+    container
+        .query_selector(&selector)
+        .ok()
+        .flatten()
+        .and_then(|error| error.text_content())
+        .map(|text| text.trim().to_string())
+}
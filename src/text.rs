@@ -0,0 +1,156 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The character replacing the hidden characters of a masked value.
+pub const MASK_CHAR: char = '\u{2022}';
+
+/// mask_value
+/// Masks every character of `value` except the last `visible` ones, keeping whitespace so that grouped
+/// values such as card numbers keep their layout. Characters are counted in grapheme clusters.
+///
+/// # Arguments
+/// * `value` - The value to mask.
+/// * `visible` - The number of trailing characters left visible.
+///
+/// # Returns
+/// (String): The masked value.
+///
+/// # Examples
+/// ```
+/// use input_yew::text::mask_value;
+///
+/// assert_eq!(mask_value("4111 1111 1111 1234", 4), "•••• •••• •••• 1234");
+/// assert_eq!(mask_value("123-45-6789", 4), "•••••••6789");
+/// assert_eq!(mask_value("12", 4), "12");
+/// ```
+pub fn mask_value(value: &str, visible: usize) -> String {
+    let masked = value
+        .graphemes(true)
+        .filter(|grapheme| !grapheme.trim().is_empty())
+        .count()
+        .saturating_sub(visible);
+    let mut seen = 0;
+    value
+        .graphemes(true)
+        .map(|grapheme| {
+            if grapheme.trim().is_empty() {
+                return grapheme.to_string();
+            }
+            seen += 1;
+            if seen <= masked {
+                MASK_CHAR.to_string()
+            } else {
+                grapheme.to_string()
+            }
+        })
+        .collect()
+}
+
+/// truncate_graphemes
+/// Truncates `value` to its first `max` grapheme clusters, without splitting emoji or combining marks.
+///
+/// # Arguments
+/// * `value` - The value to truncate.
+/// * `max` - The maximum number of grapheme clusters kept.
+///
+/// # Returns
+/// (&str): The longest prefix of `value` made of at most `max` grapheme clusters.
+///
+/// # Examples
+/// ```
+/// use input_yew::text::truncate_graphemes;
+///
+/// assert_eq!(truncate_graphemes("🇫🇷🇩🇪🇮🇹", 2), "🇫🇷🇩🇪");
+/// assert_eq!(truncate_graphemes("👍🏽👍🏿", 1), "👍🏽");
+/// assert_eq!(truncate_graphemes("cafe\u{301}s", 4), "cafe\u{301}");
+/// assert_eq!(truncate_graphemes("abc", 5), "abc");
+/// ```
+pub fn truncate_graphemes(value: &str, max: usize) -> &str {
+    match value.grapheme_indices(true).nth(max) {
+        Some((index, _)) => &value[..index],
+        None => value,
+    }
+}
+
+/// insert_at_grapheme
+/// Inserts `insert` into `value` before its grapheme cluster at `index`, or at the end when `index` is past
+/// it, without splitting emoji or combining marks.
+///
+/// # Arguments
+/// * `value` - The value to insert into.
+/// * `index` - The position of the insertion, in grapheme clusters.
+/// * `insert` - The text to insert.
+///
+/// # Returns
+/// (String): The value with `insert` inserted.
+///
+/// # Examples
+/// ```
+/// use input_yew::text::insert_at_grapheme;
+///
+/// assert_eq!(insert_at_grapheme("🇫🇷🇩🇪", 1, "-"), "🇫🇷-🇩🇪");
+/// assert_eq!(insert_at_grapheme("👍🏽👍🏿", 1, " "), "👍🏽 👍🏿");
+/// assert_eq!(insert_at_grapheme("e\u{301}e\u{301}", 1, "|"), "e\u{301}|e\u{301}");
+/// assert_eq!(insert_at_grapheme("ab", 9, "c"), "abc");
+/// ```
+pub fn insert_at_grapheme(value: &str, index: usize, insert: &str) -> String {
+    let (head, tail) = value.split_at(truncate_graphemes(value, index).len());
+    format!("{head}{insert}{tail}")
+}
+
+/// reverse_graphemes
+/// Reverses `value` grapheme cluster by grapheme cluster, so that emoji and accented letters stay intact.
+///
+/// # Arguments
+/// * `value` - The value to reverse.
+///
+/// # Returns
+/// (String): The reversed value.
+///
+/// # Examples
+/// ```
+/// use input_yew::text::reverse_graphemes;
+///
+/// assert_eq!(reverse_graphemes("🇫🇷🇩🇪"), "🇩🇪🇫🇷");
+/// assert_eq!(reverse_graphemes("a👍🏽"), "👍🏽a");
+/// assert_eq!(reverse_graphemes("cafe\u{301}"), "e\u{301}fac");
+/// ```
+pub fn reverse_graphemes(value: &str) -> String {
+    value.graphemes(true).rev().collect()
+}
+
+/// truncate_decimals
+/// Truncates the digits of `value` after its decimal `separator` to at most `max` of them, e.g. to keep
+/// prices to cents while typing. Values without the separator are returned unchanged.
+///
+/// # Arguments
+/// * `value` - The number as typed.
+/// * `max` - The maximum number of decimal places.
+/// * `separator` - The decimal separator of the locale, e.g. `'.'` or `','`.
+///
+/// # Returns
+/// (&str): The value without its extra decimal places.
+///
+/// # Examples
+/// ```
+/// use input_yew::text::truncate_decimals;
+///
+/// assert_eq!(truncate_decimals("3.14159", 2, '.'), "3.14");
+/// assert_eq!(truncate_decimals("1.234,567", 2, ','), "1.234,56");
+/// assert_eq!(truncate_decimals("3.", 2, '.'), "3.");
+/// assert_eq!(truncate_decimals("3.14159", 0, '.'), "3");
+/// assert_eq!(truncate_decimals("3.", 0, '.'), "3");
+/// assert_eq!(truncate_decimals("42", 2, '.'), "42");
+/// ```
+pub fn truncate_decimals(value: &str, max: usize, separator: char) -> &str {
+    let Some(index) = value.rfind(separator) else {
+        return value;
+    };
+    if max == 0 {
+        return &value[..index];
+    }
+    let decimals_start = index + separator.len_utf8();
+    match value[decimals_start..].char_indices().nth(max) {
+        Some((end, _)) => &value[..decimals_start + end],
+        None => value,
+    }
+}
@@ -0,0 +1,259 @@
+use crate::validators::cached_regex;
+use unicode_segmentation::UnicodeSegmentation;
+use yew::prelude::*;
+
+/// The tolerance used when checking that a number aligns to its `step`.
+const STEP_TOLERANCE: f64 = 1e-9;
+
+/// The event updating and validating the value of a field.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationTrigger {
+    /// The `input` event, fired on every keystroke.
+    #[default]
+    Input,
+    /// The `change` event, fired when the value is committed: on blur, on Enter or on selection.
+    Change,
+    /// The `blur` event, fired when the user leaves the field. The value is still updated on every
+    /// keystroke, but only validated once the user is done typing.
+    Blur,
+    /// Both the `input` and the `blur` events, so that a field left untouched is validated too.
+    Both,
+}
+
+/// Options for the built-in checks run by `validate_value` before the custom validator.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ValidationOptions {
+    /// Whether a blank value is invalid.
+    pub required: bool,
+    /// The minimum number of characters of a non-empty value.
+    pub min_length: Option<usize>,
+    /// The maximum number of characters of the value.
+    pub max_length: Option<usize>,
+    /// Whether lengths are counted in grapheme clusters, so that e.g. 👨‍👩‍👧 counts as one character.
+    pub count_graphemes: bool,
+    /// A regular expression the whole of a non-empty value must match.
+    pub pattern: Option<&'static str>,
+    /// The minimum number value, also the base values are stepped from. Defaults to `0` when unset.
+    pub min: Option<f64>,
+    /// The maximum number value.
+    pub max: Option<f64>,
+    /// The step number values must align to, counted from `min`.
+    pub step: Option<f64>,
+    /// The minimum number of whitespace-separated words of a non-empty value.
+    pub min_words: Option<usize>,
+    /// The maximum number of whitespace-separated words of the value.
+    pub max_words: Option<usize>,
+    /// Whether a blank value skips the custom validator, so that an optional field checked for its format
+    /// isn't flagged when left blank.
+    pub allow_empty: bool,
+}
+
+/// A reason a value failed validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The value is blank but required.
+    Required,
+    /// The value is shorter than `min_length`.
+    TooShort,
+    /// The value is longer than `max_length`.
+    TooLong,
+    /// The value does not match `pattern`.
+    PatternMismatch,
+    /// The value is a number lower than `min`.
+    RangeUnderflow,
+    /// The value is a number greater than `max`.
+    RangeOverflow,
+    /// The value is not a whole number of `step`s away from `min`.
+    StepMismatch,
+    /// The value has fewer words than `min_words`.
+    TooFewWords,
+    /// The value has more words than `max_words`.
+    TooManyWords,
+    /// The password scores below the minimum strength of the field.
+    TooWeak,
+    /// The custom validator rejected the value.
+    Custom,
+}
+
+/// The result of validating a value.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ValidationState {
+    /// Every check the value failed, in the order they were run.
+    pub errors: Vec<ValidationError>,
+}
+
+impl ValidationError {
+    /// Returns a default message describing the error, used when no specific message is configured.
+    pub fn message(&self) -> &'static str {
+        match self {
+            ValidationError::Required => "This field is required.",
+            ValidationError::TooShort => "This value is too short.",
+            ValidationError::TooLong => "This value is too long.",
+            ValidationError::PatternMismatch => "This value does not match the expected format.",
+            ValidationError::RangeUnderflow => "This value is too low.",
+            ValidationError::RangeOverflow => "This value is too high.",
+            ValidationError::StepMismatch => "This value is not an allowed step.",
+            ValidationError::TooFewWords => "This value has too few words.",
+            ValidationError::TooManyWords => "This value has too many words.",
+            ValidationError::TooWeak => "This password is too weak.",
+            ValidationError::Custom => "This value is invalid.",
+        }
+    }
+}
+
+impl ValidationState {
+    /// Returns `true` when the value passed every check.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Returns the length of `value` in characters, or in grapheme clusters when `count_graphemes` is set.
+pub(crate) fn text_length(value: &str, count_graphemes: bool) -> usize {
+    if count_graphemes {
+        value.graphemes(true).count()
+    } else {
+        value.chars().count()
+    }
+}
+
+/// Returns the number of whitespace-separated words in `value`.
+pub(crate) fn word_count(value: &str) -> usize {
+    value.split_whitespace().count()
+}
+
+/// Returns `true` when `value` parses as a number that is not a whole number of `step`s away from `min`,
+/// mirroring the DOM `stepMismatch` validity flag with a tolerance for float precision.
+pub(crate) fn is_step_mismatch(value: &str, min: Option<f64>, step: Option<f64>) -> bool {
+    match (value.trim().parse::<f64>(), step) {
+        (Ok(value), Some(step)) if step > 0.0 => {
+            let steps = (value - min.unwrap_or(0.0)) / step;
+            (steps - steps.round()).abs() > STEP_TOLERANCE
+        }
+        _ => false,
+    }
+}
+
+/// validate_value
+/// Validates `value` against the built-in checks of `options`, then against `validator`.
+///
+/// Like native constraint validation, an empty value only fails the `required` check, while the custom
+/// validator runs so it can reject empty values itself, unless `allow_empty` is set. An invalid
+/// `pattern` is ignored, and a valid one is only compiled once, see `cached_regex`.
+///
+/// # Arguments
+/// * `value` - The value to validate.
+/// * `validator` - A custom validator returning `false` for invalid values.
+/// * `options` - The built-in checks to run.
+///
+/// # Returns
+/// (ValidationState): The failed checks, if any.
+///
+/// # Examples
+/// ```
+/// use input_yew::validation::{validate_value, ValidationError, ValidationOptions};
+/// use yew::Callback;
+///
+/// let options = ValidationOptions {
+///     required: true,
+///     min_length: Some(3),
+///     pattern: Some("[a-z]+"),
+///     ..Default::default()
+/// };
+/// let any = Callback::from(|_: String| true);
+///
+/// assert!(validate_value("abc", &any, &options).is_valid());
+/// assert_eq!(validate_value("", &any, &options).errors, vec![ValidationError::Required]);
+/// assert_eq!(
+///     validate_value("A1", &any, &options).errors,
+///     vec![ValidationError::TooShort, ValidationError::PatternMismatch]
+/// );
+///
+/// let range = ValidationOptions { min: Some(0.0), max: Some(10.0), ..Default::default() };
+/// assert!(validate_value("10", &any, &range).is_valid());
+/// assert_eq!(validate_value("11", &any, &range).errors, vec![ValidationError::RangeOverflow]);
+/// assert_eq!(validate_value("-1", &any, &range).errors, vec![ValidationError::RangeUnderflow]);
+///
+/// let non_empty = Callback::from(|value: String| !value.trim().is_empty());
+/// let optional = ValidationOptions { allow_empty: true, ..Default::default() };
+/// assert!(validate_value(" ", &non_empty, &optional).is_valid());
+/// assert!(!validate_value(" ", &non_empty, &ValidationOptions::default()).is_valid());
+/// ```
+pub fn validate_value(
+    value: &str,
+    validator: &Callback<String, bool>,
+    options: &ValidationOptions,
+) -> ValidationState {
+    let mut errors = Vec::new();
+    let length = text_length(value, options.count_graphemes);
+
+    if value.trim().is_empty() {
+        if options.required {
+            errors.push(ValidationError::Required);
+        }
+    } else {
+        if length < options.min_length.unwrap_or(0) {
+            errors.push(ValidationError::TooShort);
+        }
+        if length > options.max_length.unwrap_or(usize::MAX) {
+            errors.push(ValidationError::TooLong);
+        }
+        if let Some(pattern) = options.pattern {
+            if let Some(pattern) = cached_regex(&format!("^(?:{})$", pattern)) {
+                if !pattern.is_match(value) {
+                    errors.push(ValidationError::PatternMismatch);
+                }
+            }
+        }
+        if let Ok(number) = value.trim().parse::<f64>() {
+            if options.min.is_some_and(|min| number < min) {
+                errors.push(ValidationError::RangeUnderflow);
+            }
+            if options.max.is_some_and(|max| number > max) {
+                errors.push(ValidationError::RangeOverflow);
+            }
+        }
+        if is_step_mismatch(value, options.min, options.step) {
+            errors.push(ValidationError::StepMismatch);
+        }
+        let words = word_count(value);
+        if words < options.min_words.unwrap_or(0) {
+            errors.push(ValidationError::TooFewWords);
+        }
+        if words > options.max_words.unwrap_or(usize::MAX) {
+            errors.push(ValidationError::TooManyWords);
+        }
+    }
+
+    let skip = options.allow_empty && value.trim().is_empty();
+    if !skip && !validator.emit(value.to_string()) {
+        errors.push(ValidationError::Custom);
+    }
+
+    ValidationState { errors }
+}
+
+/// use_validation
+/// Validates a value during rendering, recomputing only when the value, validator or options change.
+///
+/// This is the validation core used by `CustomInput`, exposed for custom-rendered fields.
+///
+/// # Arguments
+/// * `value` - The value to validate.
+/// * `validator` - A custom validator returning `false` for invalid values.
+/// * `opts` - The built-in checks to run.
+///
+/// # Returns
+/// (ValidationState): The failed checks, if any.
+#[hook]
+pub fn use_validation(
+    value: &str,
+    validator: &Callback<String, bool>,
+    opts: ValidationOptions,
+) -> ValidationState {
+    let state = use_memo(
+        (value.to_string(), validator.clone(), opts),
+        |(value, validator, opts)| validate_value(value, validator, opts),
+    );
+    (*state).clone()
+}
@@ -0,0 +1,391 @@
+use regex::Regex;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+thread_local! {
+    /// The regular expressions compiled by `cached_regex`, keyed by pattern. Invalid patterns are cached
+    /// as `None` so they aren't recompiled either.
+    static REGEX_CACHE: RefCell<HashMap<String, Option<Regex>>> = RefCell::new(HashMap::new());
+}
+
+/// cached_regex
+/// Compiles `pattern` on first use and returns the cached regular expression afterwards, so validators
+/// running on every keystroke don't recompile it.
+///
+/// The cache is never evicted, so it is meant for a bounded set of patterns such as those passed to the
+/// `pattern` prop. A validator defined once for the whole app can also keep its regex in a
+/// `std::sync::LazyLock` (or `once_cell::sync::Lazy`) static instead.
+///
+/// # Arguments
+/// * `pattern` - The regular expression to compile.
+///
+/// # Returns
+/// (`Option<Regex>`): The compiled regular expression, or `None` when `pattern` is invalid.
+///
+/// # Examples
+/// ```
+/// use input_yew::validators::cached_regex;
+///
+/// let zip_code = cached_regex(r"^\d{5}$").unwrap();
+/// assert!(zip_code.is_match("75001"));
+/// assert!(cached_regex("[a-z").is_none());
+/// ```
+pub fn cached_regex(pattern: &str) -> Option<Regex> {
+    REGEX_CACHE.with(|cache| {
+        cache
+            .borrow_mut()
+            .entry(pattern.to_string())
+            .or_insert_with(|| Regex::new(pattern).ok())
+            .clone()
+    })
+}
+
+/// The special characters allowed in the local part of an email address besides letters and digits.
+const EMAIL_LOCAL_SPECIALS: &str = "!#$%&'*+/=?^_`{|}~-.";
+
+/// The maximum length of the local part of an email address.
+const EMAIL_LOCAL_MAX_LENGTH: usize = 64;
+
+/// is_valid_email
+/// Checks that `email` looks like `local@domain.tld`.
+///
+/// In strict mode only ASCII addresses are accepted. Otherwise, internationalized addresses with
+/// unicode local parts and IDN domains such as `用户@例子.公司` are accepted as well. Keep in mind that
+/// not every mail server can deliver to such addresses (they require SMTPUTF8 support), and that IDN
+/// domains are checked as typed rather than via their punycode form, so strict mode remains the safer
+/// choice unless your backend is known to handle them.
+///
+/// # Arguments
+/// * `email` - The email address to check.
+/// * `strict` - Whether to only accept ASCII addresses.
+///
+/// # Returns
+/// (bool): `true` if the address is well-formed.
+///
+/// # Examples
+/// ```
+/// use input_yew::validators::is_valid_email;
+///
+/// assert!(is_valid_email("jane.doe@example.com", true));
+/// assert!(!is_valid_email("用户@例子.公司", true));
+/// assert!(is_valid_email("用户@例子.公司", false));
+/// assert!(is_valid_email("josé@correo.españa", false));
+/// assert!(!is_valid_email("用户@例子", false));
+/// assert!(!is_valid_email("jane..doe@example.com", false));
+/// assert!(!is_valid_email("jane@-example.com", false));
+/// ```
+pub fn is_valid_email(email: &str, strict: bool) -> bool {
+    let is_letter = |c: char| if strict { c.is_ascii_alphabetic() } else { c.is_alphabetic() };
+    let is_alphanumeric = |c: char| if strict { c.is_ascii_alphanumeric() } else { c.is_alphanumeric() };
+
+    let Some((local, domain)) = email.rsplit_once('@') else {
+        return false;
+    };
+
+    let local_valid = !local.is_empty()
+        && local.chars().count() <= EMAIL_LOCAL_MAX_LENGTH
+        && !local.starts_with('.')
+        && !local.ends_with('.')
+        && !local.contains("..")
+        && local
+            .chars()
+            .all(|c| is_alphanumeric(c) || EMAIL_LOCAL_SPECIALS.contains(c));
+
+    let labels: Vec<&str> = domain.split('.').collect();
+    let domain_valid = labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| is_alphanumeric(c) || c == '-')
+        })
+        && labels
+            .last()
+            .is_some_and(|tld| tld.chars().count() >= 2 && tld.chars().all(is_letter));
+
+    local_valid && domain_valid
+}
+
+/// validate_email
+/// Validates an ASCII email address. Can be passed directly as a `validate_function`.
+///
+/// # Examples
+/// ```
+/// use input_yew::validators::validate_email;
+///
+/// assert!(validate_email("jane@example.com".to_string()));
+/// assert!(!validate_email("jane@example".to_string()));
+/// ```
+pub fn validate_email(email: String) -> bool {
+    is_valid_email(&email, true)
+}
+
+/// validate_email_intl
+/// Validates an email address, also accepting internationalized addresses. Can be passed directly as a
+/// `validate_function`. See `is_valid_email` for the tradeoffs of accepting such addresses.
+///
+/// # Examples
+/// ```
+/// use input_yew::validators::validate_email_intl;
+///
+/// assert!(validate_email_intl("用户@例子.公司".to_string()));
+/// assert!(validate_email_intl("jane@example.com".to_string()));
+/// ```
+pub fn validate_email_intl(email: String) -> bool {
+    is_valid_email(&email, false)
+}
+
+/// is_valid_url
+/// Checks that `url` is an absolute URL with one of the accepted `schemes`, a well-formed host and an
+/// optional port. Only the format is checked, not whether the URL is reachable.
+///
+/// With `require_tld`, the host must be a domain name ending in a top-level domain, so that `localhost`
+/// and IP addresses are rejected. Otherwise, single-label hosts and IPv4 or bracketed IPv6 addresses are
+/// accepted as well.
+///
+/// # Arguments
+/// * `url` - The URL to check.
+/// * `schemes` - The accepted schemes, compared ignoring case, e.g. `["https"]`.
+/// * `require_tld` - Whether the host must be a domain name with a top-level domain.
+///
+/// # Returns
+/// (bool): `true` if the URL is well-formed.
+///
+/// # Examples
+/// ```
+/// use input_yew::validators::is_valid_url;
+///
+/// assert!(is_valid_url("https://example.com/path?query#fragment", &["https"], true));
+/// assert!(is_valid_url("HTTPS://user@example.com:8443", &["https"], true));
+/// assert!(!is_valid_url("http://example.com", &["https"], true));
+/// assert!(!is_valid_url("example.com", &["https", "http"], true));
+/// assert!(!is_valid_url("https://localhost:8080", &["https"], true));
+/// assert!(is_valid_url("https://localhost:8080", &["https"], false));
+/// assert!(!is_valid_url("http://192.168.0.1", &["http"], true));
+/// assert!(is_valid_url("http://192.168.0.1/admin", &["http"], false));
+/// assert!(is_valid_url("http://[::1]:3000", &["http"], false));
+/// assert!(is_valid_url("ftp://files.example.org", &["ftp"], true));
+/// assert!(!is_valid_url("https://exa mple.com", &["https"], true));
+/// assert!(!is_valid_url("https://-example.com", &["https"], true));
+/// assert!(!is_valid_url("https://example.com:port", &["https"], true));
+/// ```
+pub fn is_valid_url<S: AsRef<str>>(url: &str, schemes: &[S], require_tld: bool) -> bool {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return false;
+    };
+    if !schemes
+        .iter()
+        .any(|allowed| allowed.as_ref().eq_ignore_ascii_case(scheme))
+        || url.chars().any(char::is_whitespace)
+    {
+        return false;
+    }
+
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host_port = authority.rsplit_once('@').map_or(authority, |(_, host_port)| host_port);
+    // The colons of a bracketed IPv6 address don't start a port
+    let (host, port) = match host_port.rfind(':') {
+        Some(index) if !host_port[index..].contains(']') => {
+            (&host_port[..index], Some(&host_port[index + 1..]))
+        }
+        _ => (host_port, None),
+    };
+
+    let port_valid = match port {
+        Some(port) => port.chars().all(|c| c.is_ascii_digit()) && port.parse::<u16>().is_ok(),
+        None => true,
+    };
+    let is_ip = host.parse::<Ipv4Addr>().is_ok()
+        || host
+            .strip_prefix('[')
+            .and_then(|host| host.strip_suffix(']'))
+            .is_some_and(|host| host.parse::<Ipv6Addr>().is_ok());
+
+    let labels: Vec<&str> = host.split('.').collect();
+    let name_valid = labels.iter().all(|label| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+    });
+    let has_tld = labels.len() >= 2
+        && labels
+            .last()
+            .is_some_and(|tld| tld.chars().count() >= 2 && tld.chars().all(char::is_alphabetic));
+
+    port_valid
+        && if is_ip {
+            !require_tld
+        } else {
+            name_valid && (has_tld || !require_tld)
+        }
+}
+
+/// validate_url
+/// Validates an `http` or `https` URL whose host has a top-level domain. Can be passed directly as a
+/// `validate_function`.
+///
+/// # Examples
+/// ```
+/// use input_yew::validators::validate_url;
+///
+/// assert!(validate_url("https://example.com".to_string()));
+/// assert!(!validate_url("example.com".to_string()));
+/// ```
+pub fn validate_url(url: String) -> bool {
+    is_valid_url(&url, &["https", "http"], true)
+}
+
+/// validate_url_with
+/// Builds a URL validator accepting only the given `schemes`, to be passed as a `validate_function`. See
+/// `is_valid_url` for the checks run.
+///
+/// # Arguments
+/// * `schemes` - The accepted schemes, e.g. `&["https"]` to only accept secure URLs.
+/// * `require_tld` - Whether the host must be a domain name with a top-level domain.
+///
+/// # Returns
+/// (impl Fn(String) -> bool): The validator.
+///
+/// # Examples
+/// ```
+/// use input_yew::validators::validate_url_with;
+///
+/// let validate_secure_url = validate_url_with(&["https"], true);
+/// assert!(validate_secure_url("https://example.com".to_string()));
+/// assert!(!validate_secure_url("http://example.com".to_string()));
+///
+/// let validate_dev_url = validate_url_with(&["http", "https"], false);
+/// assert!(validate_dev_url("http://localhost:8080".to_string()));
+/// assert!(validate_dev_url("http://127.0.0.1:8080".to_string()));
+/// ```
+pub fn validate_url_with(schemes: &[&str], require_tld: bool) -> impl Fn(String) -> bool {
+    let schemes: Vec<String> = schemes.iter().map(|scheme| scheme.to_string()).collect();
+    move |url| is_valid_url(&url, &schemes, require_tld)
+}
+
+/// Parses an ISO `YYYY-MM-DD` date, as submitted by date inputs, checking that the day exists in its
+/// month, including February 29 of leap years.
+fn parse_iso_date(value: &str) -> Option<(i32, u32, u32)> {
+    let mut parts = value.trim().splitn(3, '-');
+    let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+    let digits = |part: &str, len: usize| part.len() == len && part.chars().all(|c| c.is_ascii_digit());
+    if !digits(year, 4) || !digits(month, 2) || !digits(day, 2) {
+        return None;
+    }
+    let year: i32 = year.parse().ok()?;
+    let month: u32 = month.parse().ok()?;
+    let day: u32 = day.parse().ok()?;
+    let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return None,
+    };
+    (1..=days_in_month).contains(&day).then_some((year, month, day))
+}
+
+/// Returns the current local date in ISO `YYYY-MM-DD` format, as shown by date inputs.
+fn today_iso() -> String {
+    let now = js_sys::Date::new_0();
+    format!("{:04}-{:02}-{:02}", now.get_full_year(), now.get_month() + 1, now.get_date())
+}
+
+/// has_min_age
+/// Checks that someone born on `birth_date` is at least `years` old on `today`.
+///
+/// Dates are compared as calendar days, without time zones. Someone born on February 29 turns a year
+/// older on March 1 in non-leap years.
+///
+/// # Arguments
+/// * `birth_date` - The birth date in ISO `YYYY-MM-DD` format.
+/// * `today` - The reference date in ISO `YYYY-MM-DD` format.
+/// * `years` - The minimum age.
+///
+/// # Returns
+/// (bool): `true` if both dates are valid and the age is reached.
+///
+/// # Examples
+/// ```
+/// use input_yew::validators::has_min_age;
+///
+/// assert!(has_min_age("2006-05-15", "2024-05-15", 18));
+/// assert!(!has_min_age("2006-05-16", "2024-05-15", 18));
+/// assert!(!has_min_age("2004-02-29", "2022-02-28", 18));
+/// assert!(has_min_age("2004-02-29", "2022-03-01", 18));
+/// assert!(has_min_age("2004-02-29", "2024-02-29", 20));
+/// assert!(!has_min_age("2006-02-30", "2030-01-01", 18));
+/// assert!(!has_min_age("15/05/2006", "2030-01-01", 18));
+/// ```
+pub fn has_min_age(birth_date: &str, today: &str, years: u32) -> bool {
+    match (parse_iso_date(birth_date), parse_iso_date(today)) {
+        (Some((birth_year, birth_month, birth_day)), Some((year, month, day))) => {
+            let had_birthday = (month, day) >= (birth_month, birth_day);
+            let age = year - birth_year - i32::from(!had_birthday);
+            age >= 0 && age as u32 >= years
+        }
+        _ => false,
+    }
+}
+
+/// validate_min_age
+/// Builds a validator for the ISO value of a date input, accepting birth dates of people at least `years`
+/// old today, in the local time zone of the browser. See `has_min_age` for the leap day rule.
+///
+/// # Arguments
+/// * `years` - The minimum age.
+///
+/// # Returns
+/// (impl Fn(String) -> bool): The validator, to be passed as a `validate_function`.
+pub fn validate_min_age(years: u32) -> impl Fn(String) -> bool {
+    move |birth_date| has_min_age(&birth_date, &today_iso(), years)
+}
+
+/// validate_date_range
+/// Builds a validator for the ISO value of a date input, accepting existing dates between `min` and `max`
+/// inclusive. Either bound may be empty to leave that end open. A bound that isn't an existing ISO date,
+/// e.g. a mistyped "2024-13-01", rejects every date, so the mistake shows up instead of silently
+/// accepting any date.
+///
+/// # Arguments
+/// * `min` - The earliest accepted date in ISO `YYYY-MM-DD` format, or "".
+/// * `max` - The latest accepted date in ISO `YYYY-MM-DD` format, or "".
+///
+/// # Returns
+/// (impl Fn(String) -> bool): The validator, to be passed as a `validate_function`.
+///
+/// # Examples
+/// ```
+/// use input_yew::validators::validate_date_range;
+///
+/// let validate_2024 = validate_date_range("2024-01-01", "2024-12-31");
+/// assert!(validate_2024("2024-01-01".to_string()));
+/// assert!(validate_2024("2024-02-29".to_string()));
+/// assert!(validate_2024("2024-12-31".to_string()));
+/// assert!(!validate_2024("2023-12-31".to_string()));
+/// assert!(!validate_2024("2025-01-01".to_string()));
+/// assert!(!validate_2024("2024-02-30".to_string()));
+///
+/// let validate_future = validate_date_range("2024-06-01", "");
+/// assert!(validate_future("2099-01-01".to_string()));
+///
+/// let validate_mistyped = validate_date_range("2024-01-01", "2024-13-01");
+/// assert!(!validate_mistyped("2024-06-01".to_string()));
+/// assert!(!validate_mistyped("2099-01-01".to_string()));
+/// ```
+pub fn validate_date_range(min: &str, max: &str) -> impl Fn(String) -> bool {
+    let bound = |bound: &str| match bound.trim() {
+        "" => Some(None),
+        bound => parse_iso_date(bound).map(Some),
+    };
+    let bounds = bound(min).zip(bound(max));
+    move |date| match (parse_iso_date(&date), bounds) {
+        (Some(date), Some((min, max))) => {
+            min.is_none_or(|min| date >= min) && max.is_none_or(|max| date <= max)
+        }
+        _ => false,
+    }
+}
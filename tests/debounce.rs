@@ -0,0 +1,101 @@
+//! Browser tests for `use_debounce`, run with `wasm-pack test --headless --firefox`.
+#![cfg(target_arch = "wasm32")]
+
+use input_yew::use_debounce;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const DELAY_MS: u32 = 100;
+
+#[derive(Properties, PartialEq)]
+struct DebouncedProps {
+    /// The initial delay.
+    delay_ms: u32,
+    /// Receives the setter of the debounced value, to change it from the test.
+    setter: Rc<RefCell<Option<UseStateHandle<u32>>>>,
+    /// Receives the setter of the delay, to change it from the test.
+    delay_setter: Rc<RefCell<Option<UseStateHandle<u32>>>>,
+    /// Every value returned by the hook, in order.
+    settled: Rc<RefCell<Vec<u32>>>,
+}
+
+#[function_component(Debounced)]
+fn debounced(props: &DebouncedProps) -> Html {
+    let value_handle = use_state(|| 0);
+    let delay_handle = use_state(|| props.delay_ms);
+    *props.setter.borrow_mut() = Some(value_handle.clone());
+    *props.delay_setter.borrow_mut() = Some(delay_handle.clone());
+    let debounced = use_debounce(*value_handle, *delay_handle);
+    {
+        let settled = props.settled.clone();
+        use_effect_with(debounced, move |value| settled.borrow_mut().push(*value));
+    }
+    html! {}
+}
+
+type Setter = Rc<RefCell<Option<UseStateHandle<u32>>>>;
+
+/// Renders `Debounced` with the given initial delay, returning its value and delay setters and the
+/// values it returned.
+async fn render(delay_ms: u32) -> (Setter, Setter, Rc<RefCell<Vec<u32>>>) {
+    let setter = Rc::new(RefCell::new(None));
+    let delay_setter = Rc::new(RefCell::new(None));
+    let settled = Rc::new(RefCell::new(Vec::new()));
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .unwrap();
+    let root = document.create_element("div").unwrap();
+    yew::Renderer::<Debounced>::with_root_and_props(
+        root,
+        DebouncedProps {
+            delay_ms,
+            setter: setter.clone(),
+            delay_setter: delay_setter.clone(),
+            settled: settled.clone(),
+        },
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+    (setter, delay_setter, settled)
+}
+
+#[wasm_bindgen_test]
+async fn updates_once_after_the_delay() {
+    let (setter, _, settled) = render(DELAY_MS).await;
+    assert_eq!(*settled.borrow(), vec![0]);
+
+    // Each change within the delay restarts it
+    for value in 1..=3 {
+        setter.borrow().as_ref().unwrap().set(value);
+        sleep(Duration::from_millis(20)).await;
+    }
+    sleep(Duration::from_millis(u64::from(DELAY_MS) / 2)).await;
+    assert_eq!(*settled.borrow(), vec![0]);
+
+    sleep(Duration::from_millis(u64::from(DELAY_MS))).await;
+    assert_eq!(*settled.borrow(), vec![0, 3]);
+}
+
+#[wasm_bindgen_test]
+async fn settles_on_the_current_value_when_a_delay_is_set() {
+    let (setter, delay_setter, settled) = render(0).await;
+    setter.borrow().as_ref().unwrap().set(1);
+    sleep(Duration::ZERO).await;
+    assert_eq!(*settled.borrow(), vec![0, 1]);
+
+    delay_setter.borrow().as_ref().unwrap().set(DELAY_MS);
+    sleep(Duration::ZERO).await;
+    assert_eq!(*settled.borrow(), vec![0, 1]);
+
+    setter.borrow().as_ref().unwrap().set(2);
+    sleep(Duration::ZERO).await;
+    assert_eq!(*settled.borrow(), vec![0, 1]);
+    sleep(Duration::from_millis(u64::from(DELAY_MS) * 2)).await;
+    assert_eq!(*settled.borrow(), vec![0, 1, 2]);
+}
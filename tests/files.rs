@@ -0,0 +1,55 @@
+//! Browser tests for the file metadata, run with `wasm-pack test --headless --firefox`.
+#![cfg(target_arch = "wasm32")]
+
+use input_yew::files::{files_meta, FileMeta};
+use js_sys::Array;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use web_sys::{DataTransfer, File, FilePropertyBag};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn file(name: &str, content: &str, mime_type: &str, last_modified: f64) -> File {
+    let options = FilePropertyBag::new();
+    options.set_type(mime_type);
+    options.set_last_modified(last_modified);
+    let parts = Array::of1(&JsValue::from_str(content));
+    File::new_with_str_sequence_and_options(&parts, name, &options).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn reads_the_metadata_of_each_file_in_selection_order() {
+    // A `DataTransfer` is the only way to build a `FileList` outside of a file input
+    let transfer = DataTransfer::new().unwrap();
+    let items = transfer.items();
+    items
+        .add_with_file(&file("avatar.png", "png", "image/png", 1_700_000_000_000.0))
+        .unwrap();
+    items
+        .add_with_file(&file("notes.txt", "hello", "", 0.0))
+        .unwrap();
+
+    assert_eq!(
+        files_meta(&transfer.files().unwrap()),
+        vec![
+            FileMeta {
+                name: "avatar.png".to_string(),
+                size: 3.0,
+                mime_type: "image/png".to_string(),
+                last_modified: 1_700_000_000_000.0,
+            },
+            FileMeta {
+                name: "notes.txt".to_string(),
+                size: 5.0,
+                mime_type: String::new(),
+                last_modified: 0.0,
+            },
+        ]
+    );
+}
+
+#[wasm_bindgen_test]
+fn reads_nothing_from_an_empty_list() {
+    let transfer = DataTransfer::new().unwrap();
+    assert!(files_meta(&transfer.files().unwrap()).is_empty());
+}
@@ -0,0 +1,58 @@
+//! Browser tests for the tel input, run with `wasm-pack test --headless --firefox`.
+#![cfg(all(target_arch = "wasm32", feature = "phone"))]
+
+use input_yew::CustomInput;
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Element, Event, EventInit, HtmlInputElement, HtmlSelectElement};
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[function_component(Phone)]
+fn phone() -> Html {
+    let phone_handle = use_state(String::default);
+    let phone_valid_handle = use_state(|| true);
+    html! {
+        <CustomInput
+            input_type={"tel"}
+            name={"phone"}
+            input_ref={NodeRef::default()}
+            input_handle={phone_handle}
+            input_valid_handle={phone_valid_handle}
+            validate_function={Callback::from(|_: String| true)}
+        />
+    }
+}
+
+fn query<T: JsCast>(root: &Element, selector: &str) -> T {
+    root.query_selector(selector)
+        .unwrap()
+        .unwrap()
+        .dyn_into::<T>()
+        .unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn selecting_a_country_reads_the_select_value() {
+    let document = web_sys::window().and_then(|window| window.document()).unwrap();
+    let root = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&root).unwrap();
+    yew::Renderer::<Phone>::with_root(root.clone()).render();
+    sleep(Duration::ZERO).await;
+
+    let select: HtmlSelectElement = query(&root, "select");
+    select.set_value("France");
+    let init = EventInit::new();
+    init.set_bubbles(true);
+    select
+        .dispatch_event(&Event::new_with_event_init_dict("change", &init).unwrap())
+        .unwrap();
+    sleep(Duration::ZERO).await;
+
+    let tel: HtmlInputElement = query(&root, "input[type=tel]");
+    assert_eq!(tel.value(), "+33");
+    root.remove();
+}
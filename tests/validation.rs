@@ -0,0 +1,69 @@
+//! Browser tests for the validation of `CustomInput`, run with
+//! `wasm-pack test --headless --firefox -- --features testing`.
+#![cfg(all(target_arch = "wasm32", feature = "testing"))]
+
+use input_yew::testing::{get_error_text, type_into};
+use input_yew::CustomInput;
+use std::time::Duration;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const DEBOUNCE_MS: u32 = 100;
+
+#[derive(Properties, PartialEq)]
+struct BioProps {
+    input_ref: NodeRef,
+}
+
+#[function_component(Bio)]
+fn bio(props: &BioProps) -> Html {
+    let bio_handle = use_state(String::default);
+    let bio_valid_handle = use_state(|| true);
+    html! {
+        <CustomInput
+            name={"bio"}
+            input_ref={props.input_ref.clone()}
+            input_handle={bio_handle}
+            input_valid_handle={bio_valid_handle}
+            validate_function={Callback::from(|bio: String| !bio.contains("http"))}
+            error_message={"Links are not allowed."}
+            form_input_error_class={"error-txt"}
+            debounce_ms={DEBOUNCE_MS}
+        />
+    }
+}
+
+#[wasm_bindgen_test]
+async fn debounced_validity_lags_behind_typing() {
+    let document = web_sys::window().and_then(|window| window.document()).unwrap();
+    let root = document.create_element("div").unwrap();
+    let input_ref = NodeRef::default();
+    yew::Renderer::<Bio>::with_root_and_props(
+        root.clone(),
+        BioProps {
+            input_ref: input_ref.clone(),
+        },
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    type_into(&input_ref, "see http").await;
+    // The value is stored right away, but still validated as it was before the edit
+    assert_eq!(get_error_text(&root, "error-txt"), None);
+
+    sleep(Duration::from_millis(u64::from(DEBOUNCE_MS) * 2)).await;
+    assert_eq!(
+        get_error_text(&root, "error-txt").as_deref(),
+        Some("Links are not allowed.")
+    );
+
+    type_into(&input_ref, "see").await;
+    // The error stays until the corrected value settles
+    assert!(get_error_text(&root, "error-txt").is_some());
+
+    sleep(Duration::from_millis(u64::from(DEBOUNCE_MS) * 2)).await;
+    assert_eq!(get_error_text(&root, "error-txt"), None);
+}